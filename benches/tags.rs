@@ -0,0 +1,120 @@
+//! Compares the ordered `Vec` representation of [`Tags`] against the previous `HashMap` one
+//!
+//! Run with `cargo bench --bench tags`
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use twitch_message::{Parse as _, Tags};
+
+const INPUT: &str = r"@badge-info=subscriber/8;badges=broadcaster/1,subscriber/6,premium/1;client-nonce=0a1b2c3d4e5f60718293a4b5c6d7e8f9;color=#FF69B4;display-name=museun;emotes=25:0-4,12-16/1902:6-10;first-msg=0;flags=;id=d7f8a1e2-4b3c-4d5e-8f9a-0b1c2d3e4f50;mod=0;returning-chatter=0;room-id=23196011;subscriber=1;system-msg=museun\shas\ssubscribed\sfor\s8\smonths!;tmi-sent-ts=1669340813893;turbo=0;user-id=23196011;user-type= :museun!museun@museun.tmi.twitch.tv PRIVMSG #museun :Kappa Keepo Kappa";
+
+/// The previous `HashMap` based representation, which eagerly unescaped every value
+mod map {
+    use std::{borrow::Cow, collections::HashMap};
+
+    use twitch_message::escape;
+
+    pub struct Tags<'a> {
+        inner: HashMap<Cow<'a, str>, Cow<'a, str>>,
+    }
+
+    impl<'a> Tags<'a> {
+        pub fn parse(input: &mut &'a str) -> Self {
+            let (head, tail) = input.split_once(' ').unwrap();
+            *input = tail;
+
+            let inner = head[1..]
+                .split_terminator(';')
+                .flat_map(|tag| tag.split_once('='))
+                .map(|(k, v)| (Cow::from(k), escape::unescape_tag(v)))
+                .collect();
+            Self { inner }
+        }
+
+        pub fn get(&self, key: &str) -> Option<&str> {
+            self.inner.get(key).map(|s| &**s).filter(|s| !s.is_empty())
+        }
+
+        pub fn to_raw(&self) -> String {
+            self.inner
+                .iter()
+                .enumerate()
+                .fold(String::new(), |mut s, (i, (k, v))| {
+                    s.push(if i == 0 { '@' } else { ';' });
+                    s.push_str(k);
+                    s.push('=');
+                    s.push_str(&escape::escape_tag(v));
+                    s
+                })
+        }
+    }
+}
+
+const KEYS: [&str; 4] = ["color", "room-id", "user-type", "system-msg"];
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    group.bench_function("vec", |b| {
+        b.iter(|| Tags::parse(&mut black_box(INPUT)).unwrap())
+    });
+    group.bench_function("map", |b| {
+        b.iter(|| map::Tags::parse(&mut black_box(INPUT)))
+    });
+    group.finish();
+}
+
+fn get(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get");
+
+    let tags = Tags::parse(&mut &*INPUT).unwrap();
+    group.bench_function("vec", |b| {
+        b.iter(|| {
+            for key in KEYS {
+                black_box(tags.get(black_box(key)));
+            }
+        })
+    });
+
+    let tags = map::Tags::parse(&mut &*INPUT);
+    group.bench_function("map", |b| {
+        b.iter(|| {
+            for key in KEYS {
+                black_box(tags.get(black_box(key)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn parse_and_get(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_and_get");
+    group.bench_function("vec", |b| {
+        b.iter(|| {
+            let tags = Tags::parse(&mut black_box(INPUT)).unwrap();
+            for key in KEYS {
+                black_box(tags.get(key));
+            }
+        })
+    });
+    group.bench_function("map", |b| {
+        b.iter(|| {
+            let tags = map::Tags::parse(&mut black_box(INPUT));
+            for key in KEYS {
+                black_box(tags.get(key));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn to_raw(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_raw");
+
+    let tags = Tags::parse(&mut &*INPUT).unwrap();
+    group.bench_function("vec", |b| b.iter(|| black_box(&tags).to_raw()));
+
+    let tags = map::Tags::parse(&mut &*INPUT);
+    group.bench_function("map", |b| b.iter(|| black_box(&tags).to_raw()));
+    group.finish();
+}
+
+criterion_group!(benches, parse, get, parse_and_get, to_raw);
+criterion_main!(benches);
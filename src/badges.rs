@@ -1,7 +1,8 @@
-use std::borrow::Cow;
+use alloc::{borrow::Cow, format, string::String};
 
 use crate::{
-    messages::{BadgeSetIdRef, ChatBadgeIdRef, IntoCow},
+    known_tags::KnownTag,
+    messages::{BadgeSetIdRef, ChatBadgeIdRef, IntoCow, SubPlan},
     Tags,
 };
 
@@ -65,10 +66,168 @@ impl<'a> Badge<'a> {
     ///
     /// If you already have a **badges** tag, you can use [`parse_badges`]
     pub fn from_tags<'t: 'a>(tags: &'t Tags<'a>) -> impl Iterator<Item = Badge<'a>> + 't {
-        tags.get("badges").into_iter().flat_map(parse_badges)
+        tags.get(KnownTag::Badges)
+            .into_iter()
+            .flat_map(parse_badges)
     }
 }
 
+impl<'a> Badge<'a> {
+    /// Get the [`KnownBadge`] for this badge
+    ///
+    /// ```rust
+    /// use twitch_message::{parse_badges, KnownBadge, messages::SubPlan};
+    ///
+    /// let badges = parse_badges("moderator/1,subscriber/3012,bits/1000,foo/bar")
+    ///     .map(|badge| badge.known())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(badges[0], KnownBadge::Moderator);
+    /// assert_eq!(badges[1], KnownBadge::Subscriber { tier: SubPlan::Tier3, months: 12 });
+    /// assert_eq!(badges[2], KnownBadge::Bits(1000));
+    /// assert_eq!(badges[3].name(), "foo");
+    /// ```
+    pub fn known(&self) -> KnownBadge<'a> {
+        KnownBadge::from(self.clone())
+    }
+}
+
+/// A known [`Badge`], with its version parsed
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[non_exhaustive]
+pub enum KnownBadge<'a> {
+    /// The broadcaster of the channel
+    Broadcaster,
+    /// A moderator in the channel
+    Moderator,
+    /// A VIP in the channel
+    Vip,
+    /// A subscriber of the channel
+    Subscriber {
+        /// The tier of the subscription
+        tier: SubPlan<'a>,
+        /// The months of the badge, this is the tenure the badge is for (e.g. `12` for 1 year), see [`badge_info`](crate::messages::Privmsg::badge_info) for the actual months
+        months: u32,
+    },
+    /// A founder of the channel
+    Founder,
+    /// Twitch staff
+    Staff,
+    /// A Twitch admin
+    Admin,
+    /// A global moderator
+    GlobalMod,
+    /// A Twitch partner
+    Partner,
+    /// An artist in the channel
+    Artist,
+    /// The amount of bits cheered in the channel (e.g. `1000`)
+    Bits(u64),
+    /// The rank on the bits leaderboard of the channel
+    BitsLeader(u32),
+    /// The amount of subscriptions gifted in the channel (e.g. `50`)
+    SubGifter(u64),
+    /// The rank on the sub gift leaderboard of the channel
+    SubGiftLeader(u32),
+    /// A Prime Gaming user
+    Premium,
+    /// A Turbo user
+    Turbo,
+    /// Watching without audio
+    NoAudio,
+    /// Watching without video
+    NoVideo,
+    /// Any other badge, or a known badge with a version that could not be parsed
+    Other(Badge<'a>),
+}
+
+impl KnownBadge<'_> {
+    /// The name (set id) of the badge (e.g. `moderator`)
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Broadcaster => "broadcaster",
+            Self::Moderator => "moderator",
+            Self::Vip => "vip",
+            Self::Subscriber { .. } => "subscriber",
+            Self::Founder => "founder",
+            Self::Staff => "staff",
+            Self::Admin => "admin",
+            Self::GlobalMod => "global_mod",
+            Self::Partner => "partner",
+            Self::Artist => "artist-badge",
+            Self::Bits(..) => "bits",
+            Self::BitsLeader(..) => "bits-leader",
+            Self::SubGifter(..) => "sub-gifter",
+            Self::SubGiftLeader(..) => "sub-gift-leader",
+            Self::Premium => "premium",
+            Self::Turbo => "turbo",
+            Self::NoAudio => "no_audio",
+            Self::NoVideo => "no_video",
+            Self::Other(badge) => badge.name.as_str(),
+        }
+    }
+}
+
+impl<'a> From<Badge<'a>> for KnownBadge<'a> {
+    fn from(badge: Badge<'a>) -> Self {
+        let version = badge.version.as_str();
+        let known = match badge.name.as_str() {
+            "broadcaster" => Some(Self::Broadcaster),
+            "moderator" => Some(Self::Moderator),
+            "vip" => Some(Self::Vip),
+            "subscriber" => version.parse::<u32>().ok().map(|version| {
+                // the thousands are the tier, e.g. `2006` is a 6 month tier 2 badge
+                let tier = match version / 1000 {
+                    0 | 1 => SubPlan::Tier1,
+                    2 => SubPlan::Tier2,
+                    3 => SubPlan::Tier3,
+                    tier => SubPlan::Other(Cow::Owned(format!("{tier}000"))),
+                };
+                Self::Subscriber {
+                    tier,
+                    months: version % 1000,
+                }
+            }),
+            "founder" => Some(Self::Founder),
+            "staff" => Some(Self::Staff),
+            "admin" => Some(Self::Admin),
+            "global_mod" => Some(Self::GlobalMod),
+            "partner" => Some(Self::Partner),
+            "artist-badge" => Some(Self::Artist),
+            "bits" => version.parse().ok().map(Self::Bits),
+            "bits-leader" => version.parse().ok().map(Self::BitsLeader),
+            "sub-gifter" => version.parse().ok().map(Self::SubGifter),
+            "sub-gift-leader" => version.parse().ok().map(Self::SubGiftLeader),
+            "premium" => Some(Self::Premium),
+            "turbo" => Some(Self::Turbo),
+            "no_audio" => Some(Self::NoAudio),
+            "no_video" => Some(Self::NoVideo),
+            _ => None,
+        };
+        known.unwrap_or(Self::Other(badge))
+    }
+}
+
+/// How many months the user has been subscribed, from the `badge-info` tag or the `subscriber` or `founder` badge
+pub(crate) fn subscriber_months(tags: &Tags<'_>) -> Option<u32> {
+    let exact = tags
+        .get(KnownTag::BadgeInfo)
+        .into_iter()
+        .flat_map(parse_badges)
+        .find(|badge| matches!(badge.name.as_str(), "subscriber" | "founder"))
+        .and_then(|badge| badge.version.as_str().parse().ok());
+
+    exact.or_else(|| {
+        Badge::from_tags(tags).find_map(|badge| match badge.known() {
+            KnownBadge::Subscriber { months, .. } => Some(months.max(1)),
+            // founders are subscribers, but their badge doesn't have a tenure
+            KnownBadge::Founder => Some(1),
+            _ => None,
+        })
+    })
+}
+
 /// Currently an alias for [`Badge`]
 pub type BadgeInfo<'a> = Badge<'a>;
 
@@ -95,3 +254,75 @@ impl Badge<'_> {
             .into();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parse as _;
+
+    #[test]
+    fn known_badges() {
+        let input = "broadcaster/1,subscriber/2024,founder/0,bits/100000,\
+            sub-gifter/50,bits-leader/2,artist-badge/1,premium/1,bits/lots,predictions/blue-1";
+
+        let badges = parse_badges(input).map(|b| b.known()).collect::<Vec<_>>();
+        assert_eq!(
+            badges[..9],
+            [
+                KnownBadge::Broadcaster,
+                KnownBadge::Subscriber {
+                    tier: SubPlan::Tier2,
+                    months: 24
+                },
+                KnownBadge::Founder,
+                KnownBadge::Bits(100_000),
+                KnownBadge::SubGifter(50),
+                KnownBadge::BitsLeader(2),
+                KnownBadge::Artist,
+                KnownBadge::Premium,
+                KnownBadge::Other(Badge {
+                    name: Cow::Borrowed("bits".into()),
+                    version: Cow::Borrowed("lots".into()),
+                }),
+            ]
+        );
+        assert_eq!(badges[9].name(), "predictions");
+
+        for badge in parse_badges(input) {
+            assert_eq!(badge.known().name(), badge.name.as_str());
+        }
+    }
+
+    #[test]
+    fn subscriber_tier() {
+        for (input, tier, months) in [
+            ("subscriber/0", SubPlan::Tier1, 0),
+            ("subscriber/12", SubPlan::Tier1, 12),
+            ("subscriber/2006", SubPlan::Tier2, 6),
+            ("subscriber/3024", SubPlan::Tier3, 24),
+            ("subscriber/4003", SubPlan::Other(Cow::from("4000")), 3),
+        ] {
+            let badge = parse_badges(input).next().unwrap();
+            assert_eq!(
+                badge.known(),
+                KnownBadge::Subscriber { tier, months },
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn subscriber_months() {
+        for (input, expected) in [
+            ("@badge-info=subscriber/14;badges=subscriber/12 ", Some(14)),
+            ("@badge-info=founder/3;badges=founder/0 ", Some(3)),
+            ("@badges=subscriber/3006 ", Some(6)),
+            ("@badges=subscriber/0 ", Some(1)),
+            ("@badges=moderator/1 ", None),
+            ("@badge-info=;badges= ", None),
+        ] {
+            let tags = Tags::parse(&mut &*input).unwrap();
+            assert_eq!(super::subscriber_months(&tags), expected, "{input}");
+        }
+    }
+}
@@ -0,0 +1,171 @@
+/// The prefixes of Twitch's global cheermotes
+///
+/// Channels can have their own cheermotes, which aren't included in the IRC stream, so you may want to provide your own list (e.g. from the [`Get Cheermotes`](https://dev.twitch.tv/docs/api/reference/#get-cheermotes) API).
+pub const DEFAULT_CHEERMOTE_PREFIXES: &[&str] = &[
+    "Cheer",
+    "DoodleCheer",
+    "BibleThump",
+    "cheerwhal",
+    "Corgo",
+    "Scoops",
+    "uni",
+    "ShowLove",
+    "Party",
+    "SeemsGood",
+    "Pride",
+    "Kappa",
+    "FrankerZ",
+    "HeyGuys",
+    "DansGame",
+    "EleGiggle",
+    "TriHard",
+    "Kreygasm",
+    "4Head",
+    "SwiftRage",
+    "NotLikeThis",
+    "FailFish",
+    "VoHiYo",
+    "PJSalt",
+    "MrDestructoid",
+    "bday",
+    "RIPCheer",
+    "Shamrock",
+    "BitBoss",
+    "Streamlabs",
+    "Muxy",
+    "HolidayCheer",
+    "Goal",
+    "Anon",
+    "Charity",
+];
+
+/// A cheermote (e.g. `Cheer100`) found in a message
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Cheermote<'a> {
+    /// The prefix of the cheermote, as it was written in the message (e.g. `Cheer`)
+    pub prefix: &'a str,
+    /// The amount of bits cheered with this cheermote
+    pub amount: u64,
+    /// The byte position of the cheermote in the provided `data`
+    pub byte_pos: (usize, usize),
+}
+
+/// Parse the cheermotes in a message
+///
+/// A cheermote is a whitespace-separated word made of one of the `prefixes` (matched case-insensitively) followed by an amount.
+///
+/// ```rust
+/// use twitch_message::{parse_cheermotes, Cheermote, DEFAULT_CHEERMOTE_PREFIXES};
+///
+/// let data = "cheer100 great stream PogChamp500 Cheer";
+///
+/// let cheers = parse_cheermotes(data, DEFAULT_CHEERMOTE_PREFIXES).collect::<Vec<_>>();
+/// assert_eq!(cheers, [Cheermote { prefix: "cheer", amount: 100, byte_pos: (0, 8) }]);
+///
+/// let cheers = parse_cheermotes(data, &["Cheer", "PogChamp"]).collect::<Vec<_>>();
+/// assert_eq!(cheers.len(), 2);
+/// assert_eq!(&data[cheers[1]], "PogChamp500");
+/// ```
+///
+/// ## See also
+/// If you have a [`Privmsg`](crate::messages::Privmsg), you can use [`Privmsg::cheermotes`](crate::messages::Privmsg::cheermotes)
+pub fn parse_cheermotes<'a: 'p, 'p>(
+    data: &'a str,
+    prefixes: &'p [&'p str],
+) -> impl Iterator<Item = Cheermote<'a>> + 'p {
+    data.split_ascii_whitespace().filter_map(move |word| {
+        let (prefix, amount) = parse_cheermote(word, prefixes)?;
+        // the words are subslices of the data
+        let start = word.as_ptr() as usize - data.as_ptr() as usize;
+        Some(Cheermote {
+            prefix,
+            amount,
+            byte_pos: (start, start + word.len()),
+        })
+    })
+}
+
+/// Parse a single word as a cheermote, returning its prefix and amount
+pub(crate) fn parse_cheermote<'a>(word: &'a str, prefixes: &[&str]) -> Option<(&'a str, u64)> {
+    let (prefix, amount) = prefixes.iter().find_map(|prefix| {
+        let head = word.get(..prefix.len())?;
+        let amount = &word[prefix.len()..];
+        (head.eq_ignore_ascii_case(prefix)
+            && !amount.is_empty()
+            && amount.bytes().all(|c| c.is_ascii_digit()))
+        .then_some((head, amount))
+    })?;
+
+    let amount = amount.parse().ok().filter(|&amount| amount > 0)?;
+    Some((prefix, amount))
+}
+
+impl<'a> core::ops::Index<Cheermote<'a>> for str {
+    type Output = str;
+    fn index(&self, index: Cheermote<'a>) -> &Self::Output {
+        let (s, e) = index.byte_pos;
+        &self[s..e]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn cheermotes() {
+        let data = "Cheer100 hello RIPCheer1 Kappa Cheer0 cheer10a uni50 \u{1F600}Cheer1 Cheer1";
+        let cheers = parse_cheermotes(data, DEFAULT_CHEERMOTE_PREFIXES).collect::<Vec<_>>();
+        assert_eq!(
+            cheers,
+            [
+                Cheermote {
+                    prefix: "Cheer",
+                    amount: 100,
+                    byte_pos: (0, 8)
+                },
+                Cheermote {
+                    prefix: "RIPCheer",
+                    amount: 1,
+                    byte_pos: (15, 24)
+                },
+                Cheermote {
+                    prefix: "uni",
+                    amount: 50,
+                    byte_pos: (47, 52)
+                },
+                Cheermote {
+                    prefix: "Cheer",
+                    amount: 1,
+                    byte_pos: (64, 70)
+                },
+            ]
+        );
+        for cheer in cheers {
+            assert!(data[cheer].starts_with(cheer.prefix));
+        }
+
+        assert_eq!(parse_cheermotes(data, &[]).count(), 0);
+    }
+
+    #[test]
+    fn prefixes_dont_outlive_data() {
+        fn parse(data: &'static str) -> Vec<Cheermote<'static>> {
+            let prefixes = alloc::vec![alloc::string::String::from("PogChamp")];
+            let prefixes = prefixes.iter().map(|s| &**s).collect::<Vec<_>>();
+            parse_cheermotes(data, &prefixes).collect()
+        }
+
+        let cheers = parse("PogChamp100 hello");
+        assert_eq!(
+            cheers,
+            [Cheermote {
+                prefix: "PogChamp",
+                amount: 100,
+                byte_pos: (0, 11)
+            }]
+        );
+    }
+}
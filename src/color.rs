@@ -59,15 +59,15 @@ impl Default for Color {
     }
 }
 
-impl std::fmt::Display for Color {
+impl core::fmt::Display for Color {
     /// Constructs a `#RRGGBB` string from this type
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let Self(r, g, b) = self;
         write!(f, "#{r:02X}{g:02X}{b:02X}")
     }
 }
 
-impl std::str::FromStr for Color {
+impl core::str::FromStr for Color {
     type Err = &'static str;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let input = match input.len() {
@@ -1,6 +1,11 @@
-use std::borrow::Cow;
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use crate::{
+    known_tags::KnownTag,
     messages::{EmoteIdRef, IntoCow},
     Tags,
 };
@@ -42,7 +47,7 @@ impl<'a> Emote<'a> {
         tags: &'t Tags<'a>,
         data: &'a str,
     ) -> impl Iterator<Item = Emote<'a>> + 't {
-        tags.get("emotes")
+        tags.get(KnownTag::Emotes)
             .into_iter()
             .flat_map(|input| parse_emotes(input, data))
     }
@@ -50,6 +55,9 @@ impl<'a> Emote<'a> {
 
 /// Parse emotes from a tag value and associated data
 ///
+/// Emotes with ranges that are malformed, outside of the `data` or that don't match the text of an earlier emote with the same id are skipped.
+/// Use [`try_parse_emotes`] to find out about these.
+///
 /// ```rust
 /// use twitch_message::{Tags, parse_emotes, Emote};
 /// use std::borrow::Cow;
@@ -67,39 +75,281 @@ impl<'a> Emote<'a> {
 /// ## See also
 /// If you have an already parsed [`Tags`] you can use [`Emote::from_tags`]
 pub fn parse_emotes<'a>(input: &'a str, data: &'a str) -> impl Iterator<Item = Emote<'a>> + 'a {
-    fn substr(data: &str, start: &mut usize, end: &mut usize) -> Cow<'static, str> {
-        let (s, e) = (*start, *end);
-        *start = data.chars().map(|s| s.len_utf8()).take(s).sum();
-        *end = data.chars().map(|s| s.len_utf8()).take(e).sum();
-        data.chars().skip(s).take(e).collect()
+    EmoteParser::new(input, data).flatten()
+}
+
+/// Parse emotes from a tag value and associated data, failing on the first bad emote
+///
+/// ```rust
+/// use twitch_message::{try_parse_emotes, EmoteError};
+///
+/// let data = "Kappa testing Kappa";
+/// assert_eq!(try_parse_emotes("25:0-4,14-18", data).unwrap().len(), 2);
+///
+/// let err = try_parse_emotes("25:0-4,14-19", data).unwrap_err();
+/// assert_eq!(err, EmoteError::OutOfRange { id: "25".into(), start: 14, end: 19 });
+///
+/// let err = try_parse_emotes("25:0-4,6-10", data).unwrap_err();
+/// assert_eq!(err, EmoteError::Mismatch { id: "25".into(), start: 6, end: 10 });
+/// ```
+pub fn try_parse_emotes<'a>(input: &'a str, data: &'a str) -> Result<Vec<Emote<'a>>, EmoteError> {
+    EmoteParser::new(input, data).collect()
+}
+
+/// Errors for [`try_parse_emotes`]
+///
+/// The positions are the code point positions Twitch sent
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EmoteError {
+    /// An emote, or one of its ranges, could not be parsed
+    Malformed {
+        /// The malformed part of the emote string
+        input: String,
+    },
+    /// The range of an emote is not inside of the data
+    OutOfRange {
+        /// The id of the emote
+        id: String,
+        /// The start position of the emote
+        start: usize,
+        /// The (inclusive) end position of the emote
+        end: usize,
+    },
+    /// The text of an emote doesn't match the text of an earlier emote with the same id
+    Mismatch {
+        /// The id of the emote
+        id: String,
+        /// The start position of the emote
+        start: usize,
+        /// The (inclusive) end position of the emote
+        end: usize,
+    },
+}
+
+impl core::fmt::Display for EmoteError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Malformed { input } => write!(f, "malformed emote: `{input}`"),
+            Self::OutOfRange { id, start, end } => {
+                write!(f, "emote `{id}` at {start}-{end} is outside of the data")
+            }
+            Self::Mismatch { id, start, end } => {
+                write!(
+                    f,
+                    "emote `{id}` at {start}-{end} doesn't match its other uses"
+                )
+            }
+        }
     }
+}
 
-    input
-        .split('/')
-        .flat_map(|s| s.split_once(':'))
-        .flat_map(|(emote, range)| {
-            range
-                .split(',')
-                .flat_map(|c| c.split_once('-').map(|(s, e)| (s.parse(), e.parse())))
-                .flat_map(|(start, end)| Some((start.ok()?, end.ok()?)))
-                .zip(std::iter::repeat(emote))
-                .map(|((start, end), kind): ((usize, usize), _)| (kind, (start, end - start + 1)))
-        })
-        .map(|(emote, (mut start, end))| {
-            let (end, start) = (&mut (end + start), &mut start);
+impl core::error::Error for EmoteError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        None
+    }
+}
+
+struct EmoteParser<'a> {
+    data: &'a str,
+    // byte offset of every code point, and the length of the data
+    offsets: Vec<usize>,
+    // the first name seen for each id
+    seen: Vec<(&'a str, &'a str)>,
+    emotes: core::str::Split<'a, char>,
+    current: Option<(&'a str, core::str::Split<'a, char>)>,
+}
 
-            Emote {
-                id: IntoCow::into_cow(emote),
-                name: substr(data, start, end),
-                byte_pos: (*start, *end),
+impl<'a> EmoteParser<'a> {
+    fn new(input: &'a str, data: &'a str) -> Self {
+        let offsets = if input.is_empty() {
+            Vec::new()
+        } else {
+            data.char_indices()
+                .map(|(i, _)| i)
+                .chain(core::iter::once(data.len()))
+                .collect()
+        };
+
+        let mut emotes = input.split('/');
+        if input.is_empty() {
+            emotes.next();
+        }
+
+        Self {
+            data,
+            offsets,
+            seen: Vec::new(),
+            emotes,
+            current: None,
+        }
+    }
+
+    fn emote(&mut self, id: &'a str, range: &'a str) -> Result<Emote<'a>, EmoteError> {
+        let malformed = || EmoteError::Malformed {
+            input: range.to_string(),
+        };
+
+        let (start, end) = range.split_once('-').ok_or_else(malformed)?;
+        let (start, end): (usize, usize) = (
+            start.parse().map_err(|_| malformed())?,
+            end.parse().map_err(|_| malformed())?,
+        );
+
+        let bytes = |pos: usize| self.offsets.get(pos).copied();
+        let (Some(s), Some(e)) = (bytes(start), end.checked_add(1).and_then(bytes)) else {
+            return Err(EmoteError::OutOfRange {
+                id: id.to_string(),
+                start,
+                end,
+            });
+        };
+        if start > end {
+            return Err(malformed());
+        }
+
+        let name = &self.data[s..e];
+        match self.seen.iter().find(|(seen, _)| *seen == id) {
+            Some((_, seen)) if *seen != name => {
+                return Err(EmoteError::Mismatch {
+                    id: id.to_string(),
+                    start,
+                    end,
+                })
             }
+            Some(..) => {}
+            None => self.seen.push((id, name)),
+        }
+
+        Ok(Emote {
+            id: IntoCow::into_cow(id),
+            name: Cow::Borrowed(name),
+            byte_pos: (s, e),
         })
+    }
 }
 
-impl<'a> std::ops::Index<&Emote<'a>> for str {
+impl<'a> Iterator for EmoteParser<'a> {
+    type Item = Result<Emote<'a>, EmoteError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((id, ranges)) = &mut self.current {
+                let id = *id;
+                if let Some(range) = ranges.next() {
+                    return Some(self.emote(id, range));
+                }
+                self.current = None;
+            }
+
+            let emote = self.emotes.next()?;
+            let Some((id, ranges)) = emote.split_once(':') else {
+                return Some(Err(EmoteError::Malformed {
+                    input: emote.to_string(),
+                }));
+            };
+            self.current = Some((id, ranges.split(',')));
+        }
+    }
+}
+
+impl<'a> core::ops::Index<&Emote<'a>> for str {
     type Output = str;
     fn index(&self, index: &Emote<'a>) -> &Self::Output {
         let (s, e) = index.byte_pos;
         &self[s..e]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names<'a>(emotes: &'a [Emote<'_>], data: &'a str) -> Vec<&'a str> {
+        emotes.iter().map(|emote| &data[emote]).collect()
+    }
+
+    #[test]
+    fn multibyte() {
+        let data = "h\u{e9}llo Kappa \u{1F600}\u{1F600} Kappa \u{1F468}\u{200D}\u{1F469} Keepo";
+        let emotes = try_parse_emotes("25:6-10,15-19/1902:25-29", data).unwrap();
+        assert_eq!(names(&emotes, data), ["Kappa", "Kappa", "Keepo"]);
+        assert_eq!(emotes[0].byte_pos, (7, 12));
+        assert_eq!(emotes[1].byte_pos, (22, 27));
+        assert_eq!(emotes[2].byte_pos, (40, 45));
+
+        // names are borrowed from the data
+        assert!(emotes
+            .iter()
+            .all(|emote| matches!(emote.name, Cow::Borrowed(..))));
+    }
+
+    #[test]
+    fn emoji_emotes() {
+        let data = "\u{1F600}\u{1F600}:)\u{1F600}";
+        let emotes = try_parse_emotes("1:2-3", data).unwrap();
+        assert_eq!(names(&emotes, data), [":)"]);
+        assert_eq!(emotes[0].byte_pos, (8, 10));
+    }
+
+    #[test]
+    fn errors() {
+        let data = "Kappa \u{1F600} Kappa";
+        let overflow = alloc::format!("25:0-{}", usize::MAX);
+        for (input, expected) in [
+            (
+                "25:0-4,8-13",
+                EmoteError::OutOfRange {
+                    id: "25".into(),
+                    start: 8,
+                    end: 13,
+                },
+            ),
+            (
+                "25:0-4,2-6",
+                EmoteError::Mismatch {
+                    id: "25".into(),
+                    start: 2,
+                    end: 6,
+                },
+            ),
+            (
+                "25:4-0",
+                EmoteError::Malformed {
+                    input: "4-0".into(),
+                },
+            ),
+            (
+                &*overflow,
+                EmoteError::OutOfRange {
+                    id: "25".into(),
+                    start: 0,
+                    end: usize::MAX,
+                },
+            ),
+            (
+                "25:a-4",
+                EmoteError::Malformed {
+                    input: "a-4".into(),
+                },
+            ),
+            ("25", EmoteError::Malformed { input: "25".into() }),
+        ] {
+            assert_eq!(
+                try_parse_emotes(input, data).unwrap_err(),
+                expected,
+                "{input}"
+            );
+        }
+
+        // the bad emotes are skipped
+        let emotes = parse_emotes("25:0-4,2-6,8-12,8-13/1:4-0", data).collect::<Vec<_>>();
+        assert_eq!(names(&emotes, data), ["Kappa", "Kappa"]);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(parse_emotes("", "Kappa").count(), 0);
+        assert_eq!(try_parse_emotes("", "Kappa"), Ok(Vec::new()));
+    }
+}
@@ -80,3 +80,39 @@ pub(crate) fn octo(data: &str) -> &str {
         ""
     }
 }
+
+/// Ensures all of these client-only tags have a [valid](crate::TagKey::is_valid) key
+pub(crate) fn validate_client_tags(tags: &[(&str, &str)]) -> Result<(), crate::Error> {
+    match tags
+        .iter()
+        .find(|(key, _)| !crate::TagKey::new(key).is_valid())
+    {
+        Some((key, _)) => Err(crate::Error::InvalidTagKey { key: (*key).into() }),
+        None => Ok(()),
+    }
+}
+
+/// Writes these client-only tags, starting with `sep`, returning the next separator
+///
+/// The `+` is prepended to the keys if its missing, and the values are escaped.
+/// The keys should've been checked with [`validate_client_tags`].
+pub(crate) fn client_tags<W, E>(
+    writer: &mut W,
+    apply: fn(&mut W, core::fmt::Arguments<'_>) -> Result<(), E>,
+    mut sep: char,
+    tags: &[(&str, &str)],
+) -> Result<char, E> {
+    for &(key, value) in tags {
+        let key = crate::TagKey::new(key);
+        apply(
+            writer,
+            format_args!(
+                "{sep}{plus}{key}={value}",
+                plus = if key.is_client_only() { "" } else { "+" },
+                value = crate::escape::escape_tag(value)
+            ),
+        )?;
+        sep = ';';
+    }
+    Ok(sep)
+}
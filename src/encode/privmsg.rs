@@ -1,4 +1,5 @@
-use super::octo;
+use super::validate_client_tags;
+use super::{client_tags, octo};
 
 use crate::messages::MsgIdRef;
 
@@ -8,6 +9,7 @@ pub const fn privmsg<'a>(channel: &'a str, data: &'a str) -> Privmsg<'a> {
         channel,
         data,
         reply_id: None,
+        client_tags: &[],
     }
 }
 
@@ -17,6 +19,7 @@ pub const fn reply<'a>(id: &'a MsgIdRef, channel: &'a str, data: &'a str) -> Pri
         reply_id: Some(id),
         channel,
         data,
+        client_tags: &[],
     }
 }
 
@@ -26,17 +29,46 @@ pub struct Privmsg<'a> {
     reply_id: Option<&'a MsgIdRef>,
     channel: &'a str,
     data: &'a str,
+    client_tags: &'a [(&'a str, &'a str)],
 }
 
 impl<'a> Privmsg<'a> {
+    /// Attach these client-only tags (e.g. `+client-nonce`) to the message
+    ///
+    /// The `+` is prepended to the keys if its missing, and the values are escaped.
+    ///
+    /// This returns an [`Error::InvalidTagKey`](crate::Error::InvalidTagKey) if any of the keys are [invalid](crate::TagKey::is_valid).
+    ///
+    /// ```rust
+    /// use twitch_message::encode::privmsg;
+    ///
+    /// let msg = privmsg("museun", "Kappa").client_tags(&[("client-nonce", "abc")])?;
+    /// assert_eq!(msg.to_string(), "@+client-nonce=abc PRIVMSG #museun :Kappa\r\n");
+    ///
+    /// assert!(privmsg("museun", "Kappa").client_tags(&[("client nonce", "abc")]).is_err());
+    /// # Ok::<(),Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn client_tags(self, client_tags: &'a [(&'a str, &'a str)]) -> Result<Self, crate::Error> {
+        validate_client_tags(client_tags)?;
+        Ok(Self {
+            client_tags,
+            ..self
+        })
+    }
+
     // TODO split this into chunks
     fn fmt<W, E>(
         &self,
         writer: &mut W,
         apply: fn(&mut W, core::fmt::Arguments<'_>) -> Result<(), E>,
     ) -> Result<(), E> {
+        let mut sep = '@';
         if let Some(id) = self.reply_id {
-            apply(writer, format_args!("@reply-parent-msg-id={id} "))?;
+            apply(writer, format_args!("{sep}reply-parent-msg-id={id}"))?;
+            sep = ';';
+        }
+        if client_tags(writer, apply, sep, self.client_tags)? == ';' {
+            apply(writer, format_args!(" "))?;
         }
         apply(
             writer,
@@ -63,8 +95,8 @@ impl<'a> crate::encode::fmt::Formattable for Privmsg<'a> {
     }
 }
 
-impl<'a> std::fmt::Display for Privmsg<'a> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<'a> core::fmt::Display for Privmsg<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.fmt(f, core::fmt::Write::write_fmt)
     }
 }
@@ -118,4 +150,42 @@ mod tests {
         reply.encode(&mut out).unwrap();
         assert_eq!(out, b"@reply-parent-msg-id=123456 PRIVMSG #test :Kappa\r\n");
     }
+
+    #[test]
+    fn client_tags() {
+        let msg = super::privmsg("test", "Kappa")
+            .client_tags(&[("+client-nonce", "a b"), ("foo", "1")])
+            .unwrap();
+        assert_eq!(
+            msg.to_string(),
+            "@+client-nonce=a\\sb;+foo=1 PRIVMSG #test :Kappa\r\n"
+        );
+
+        let msg = super::reply("123456".into(), "test", "Kappa")
+            .client_tags(&[("client-nonce", "abc")])
+            .unwrap();
+        assert_eq!(
+            msg.to_string(),
+            "@reply-parent-msg-id=123456;+client-nonce=abc PRIVMSG #test :Kappa\r\n"
+        );
+
+        let msg = super::privmsg("test", "Kappa")
+            .client_tags(&[("draft/reply", "a;b\\c")])
+            .unwrap();
+        assert_eq!(
+            msg.to_string(),
+            "@+draft/reply=a\\:b\\\\c PRIVMSG #test :Kappa\r\n"
+        );
+
+        for key in ["a b", "a;b=c", "+"] {
+            let tags = [("draft/reply", "1"), (key, "2")];
+            assert!(
+                matches!(
+                    super::privmsg("test", "Kappa").client_tags(&tags),
+                    Err(crate::Error::InvalidTagKey { key: invalid }) if invalid == key
+                ),
+                "{key}"
+            );
+        }
+    }
 }
@@ -36,8 +36,8 @@ impl<'a> crate::encode::fmt::Formattable for Quit<'a> {
     }
 }
 
-impl<'a> std::fmt::Display for Quit<'a> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<'a> core::fmt::Display for Quit<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.fmt(f, core::fmt::Write::write_fmt)
     }
 }
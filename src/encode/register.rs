@@ -71,8 +71,8 @@ impl<'a, const N: usize> crate::encode::fmt::Formattable for Register<'a, N> {
     }
 }
 
-impl<'a, const N: usize> std::fmt::Display for Register<'a, N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<'a, const N: usize> core::fmt::Display for Register<'a, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.fmt(f, core::fmt::Write::write_fmt)
     }
 }
@@ -1,6 +1,13 @@
+use super::client_tags;
+use super::validate_client_tags;
+
 /// Sends a private message to a user
 pub const fn whisper<'a>(user: &'a str, data: &'a str) -> Whisper<'a> {
-    Whisper { user, data }
+    Whisper {
+        user,
+        data,
+        client_tags: &[],
+    }
 }
 
 /// The type produced by [`whisper`]
@@ -8,14 +15,31 @@ pub const fn whisper<'a>(user: &'a str, data: &'a str) -> Whisper<'a> {
 pub struct Whisper<'a> {
     user: &'a str,
     data: &'a str,
+    client_tags: &'a [(&'a str, &'a str)],
 }
 
 impl<'a> Whisper<'a> {
+    /// Attach these client-only tags (e.g. `+client-nonce`) to the message
+    ///
+    /// The `+` is prepended to the keys if its missing, and the values are escaped.
+    ///
+    /// This returns an [`Error::InvalidTagKey`](crate::Error::InvalidTagKey) if any of the keys are [invalid](crate::TagKey::is_valid).
+    pub fn client_tags(self, client_tags: &'a [(&'a str, &'a str)]) -> Result<Self, crate::Error> {
+        validate_client_tags(client_tags)?;
+        Ok(Self {
+            client_tags,
+            ..self
+        })
+    }
+
     fn fmt<W, E>(
         &self,
         writer: &mut W,
         apply: fn(&mut W, core::fmt::Arguments<'_>) -> Result<(), E>,
     ) -> Result<(), E> {
+        if client_tags(writer, apply, '@', self.client_tags)? == ';' {
+            apply(writer, format_args!(" "))?;
+        }
         apply(
             writer,
             format_args!(
@@ -40,8 +64,8 @@ impl<'a> crate::encode::fmt::Formattable for Whisper<'a> {
     }
 }
 
-impl<'a> std::fmt::Display for Whisper<'a> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<'a> core::fmt::Display for Whisper<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.fmt(f, core::fmt::Write::write_fmt)
     }
 }
@@ -74,4 +98,15 @@ mod tests {
         whisper.encode(&mut out).unwrap();
         assert_eq!(out, b"PRIVMSG jtv :/w museun hello, world\r\n");
     }
+
+    #[test]
+    fn whisper_client_tags() {
+        let whisper = super::whisper("museun", "hello")
+            .client_tags(&[("client-nonce", "abc")])
+            .unwrap();
+        assert_eq!(
+            whisper.to_string(),
+            "@+client-nonce=abc PRIVMSG jtv :/w museun hello\r\n"
+        );
+    }
 }
@@ -1,11 +1,33 @@
+use alloc::{string::String, string::ToString};
+use core::ops::Range;
+
 /// Errors produced by this crate
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum Error {
     /// The message was malformed
+    #[deprecated(note = "parse failures are reported through `Error::Parse`")]
     MalformedMessage,
     /// The numeric was invalid
+    #[deprecated(note = "parse failures are reported through `Error::Parse`")]
     InvalidNumeric,
+    /// The message could not be parsed
+    Parse(ParseError),
+    /// The input was not valid UTF-8
+    InvalidUtf8 {
+        /// The byte offset, from the start of the line, of the invalid sequence
+        offset: usize,
+    },
+    /// A tag key was not [valid](crate::TagKey::is_valid)
+    InvalidTagKey {
+        /// The invalid key
+        key: String,
+    },
+    /// A line was longer than the [`Decoder`](crate::Decoder) allows
+    LineTooLong {
+        /// The maximum length of a line, in bytes
+        max: usize,
+    },
     /// Converting a type failed its type assertion
     IncorrectMessageType {
         /// Expected this type
@@ -15,11 +37,22 @@ pub enum Error {
     },
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl From<ParseError> for Error {
+    fn from(value: ParseError) -> Self {
+        Self::Parse(value)
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        #[allow(deprecated)]
         match self {
             Self::MalformedMessage => f.write_str("the message was malformed"),
             Self::InvalidNumeric => f.write_str("invalid message numeric"),
+            Self::Parse(err) => err.fmt(f),
+            Self::InvalidUtf8 { offset } => write!(f, "invalid utf-8 at byte offset {offset}"),
+            Self::InvalidTagKey { key } => write!(f, "invalid tag key: {key:?}"),
+            Self::LineTooLong { max } => write!(f, "line is longer than {max} bytes"),
             Self::IncorrectMessageType { expected, got } => {
                 write!(f, "got {got}, expected: {expected}")
             }
@@ -27,8 +60,284 @@ impl std::fmt::Display for Error {
     }
 }
 
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        None
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// The part of a message that failed to parse
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParseComponent {
+    /// The `@key=value;..` tags
+    Tags,
+    /// The `:name!user@host` prefix
+    Prefix,
+    /// The command (or numeric)
+    Command,
+    /// The arguments following the command
+    Args,
+    /// The trailing `:data` argument
+    Trailing,
+}
+
+impl ParseComponent {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Tags => "tags",
+            Self::Prefix => "prefix",
+            Self::Command => "command",
+            Self::Args => "args",
+            Self::Trailing => "trailing",
+        }
+    }
+}
+
+impl core::fmt::Display for ParseComponent {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Why a part of a message failed to parse
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorReason {
+    /// The component was not followed by a space
+    Unterminated,
+    /// The message did not contain a command
+    MissingCommand,
+    /// The numeric command does not fit in a `u16`
+    InvalidNumeric,
+    /// A tag did not have a key (e.g. `=value`)
+    MalformedTag,
+    /// A user prefix was not in the `name!user@host` form
+    MalformedPrefix,
+    /// An argument was empty (e.g. there were consecutive spaces)
+    EmptyArgument,
+}
+
+impl ParseErrorReason {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unterminated => "expected a space after this",
+            Self::MissingCommand => "missing command",
+            Self::InvalidNumeric => "invalid numeric",
+            Self::MalformedTag => "expected a tag key",
+            Self::MalformedPrefix => "expected a `name!user@host` prefix",
+            Self::EmptyArgument => "empty argument",
+        }
+    }
+}
+
+impl core::fmt::Display for ParseErrorReason {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A parse failure, pointing at where in the raw line it happened
+///
+/// ```rust
+/// use twitch_message::{Error, ParseComponent, ParseErrorReason};
+///
+/// let Err(Error::Parse(err)) = twitch_message::parse(":tmi.twitch.tv 99999 museun :hi") else {
+///     unreachable!()
+/// };
+///
+/// assert_eq!(err.span(), 15..20);
+/// assert_eq!(err.component(), ParseComponent::Command);
+/// assert_eq!(err.reason(), ParseErrorReason::InvalidNumeric);
+///
+/// let expected = "\
+/// invalid numeric in the command at 15..20
+///   :tmi.twitch.tv 99999 museun :hi
+///                  ^^^^^";
+/// assert_eq!(err.to_string(), expected);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    span: Range<usize>,
+    component: ParseComponent,
+    reason: ParseErrorReason,
+    snippet: String,
+    snippet_start: usize,
+}
+
+impl ParseError {
+    const SNIPPET_CONTEXT: usize = 32;
+
+    pub(crate) fn new(
+        input: &str,
+        span: Range<usize>,
+        component: ParseComponent,
+        reason: ParseErrorReason,
+    ) -> Self {
+        let floor = |mut pos: usize| {
+            while !input.is_char_boundary(pos) {
+                pos -= 1;
+            }
+            pos
+        };
+
+        let start = floor(span.start.saturating_sub(Self::SNIPPET_CONTEXT));
+        let end = floor((span.end + Self::SNIPPET_CONTEXT).min(input.len()));
+
+        Self {
+            snippet: input[start..end].to_string(),
+            snippet_start: start,
+            span,
+            component,
+            reason,
+        }
+    }
+
+    /// The byte span in the raw line that failed to parse
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// The part of the message that failed to parse
+    pub const fn component(&self) -> ParseComponent {
+        self.component
+    }
+
+    /// Why it failed to parse
+    pub const fn reason(&self) -> ParseErrorReason {
+        self.reason
+    }
+
+    /// A short snippet of the raw line surrounding the [`span`](Self::span)
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Range { start, end } = self.span;
+        writeln!(
+            f,
+            "{reason} in the {component} at {start}..{end}",
+            reason = self.reason,
+            component = self.component,
+        )?;
+
+        let start = start
+            .saturating_sub(self.snippet_start)
+            .min(self.snippet.len());
+        let end = end
+            .saturating_sub(self.snippet_start)
+            .min(self.snippet.len());
+        let pad = self.snippet[..start].chars().count();
+        let width = self.snippet[start..end].chars().count().max(1);
+
+        writeln!(f, "  {snippet}", snippet = self.snippet)?;
+        write!(f, "  {:pad$}{:^<width$}", "", "")
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn parse_error(input: &str) -> ParseError {
+        match crate::Parser::strict().parse(input) {
+            Err(Error::Parse(err)) => err,
+            other => panic!("expected a parse error, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn spans() {
+        for (input, span, component, reason) in [
+            (
+                "",
+                0..0,
+                ParseComponent::Command,
+                ParseErrorReason::MissingCommand,
+            ),
+            (
+                "@badges=;color=#FF0000",
+                0..22,
+                ParseComponent::Tags,
+                ParseErrorReason::Unterminated,
+            ),
+            (
+                "@color=#FF0000 :tmi.twitch.tv",
+                15..29,
+                ParseComponent::Prefix,
+                ParseErrorReason::Unterminated,
+            ),
+            (
+                "@color=#FF0000 :tmi.twitch.tv ",
+                30..30,
+                ParseComponent::Command,
+                ParseErrorReason::MissingCommand,
+            ),
+            (
+                ":tmi.twitch.tv 123456 #museun",
+                15..21,
+                ParseComponent::Command,
+                ParseErrorReason::InvalidNumeric,
+            ),
+        ] {
+            let err = parse_error(input);
+            assert_eq!(err.span(), span, "{input}");
+            assert_eq!(err.component(), component, "{input}");
+            assert_eq!(err.reason(), reason, "{input}");
+        }
+    }
+
+    #[test]
+    fn unterminated_is_not_an_error_by_default() {
+        for (input, kind) in [
+            ("@a=b", "@a=b"),
+            (":prefix", ":prefix"),
+            ("@a=b :pre", ":pre"),
+        ] {
+            let msg = crate::parse(input).unwrap().message;
+            assert_eq!(msg.kind, crate::messages::MessageKind::Unknown(kind.into()));
+            assert_eq!(msg.prefix, crate::Prefix::None);
+        }
+    }
+
+    #[test]
+    fn snippet() {
+        let tags = "a=b;".repeat(20);
+        let input = format!("@{tags} :tmi.twitch.tv 123456 #museun");
+
+        let err = parse_error(&input);
+        assert_eq!(err.span(), 97..103);
+        assert_eq!(err.snippet(), &input[65..]);
+
+        let display = err.to_string();
+        let mut lines = display.lines();
+        assert_eq!(
+            lines.next(),
+            Some("invalid numeric in the command at 97..103")
+        );
+        assert_eq!(lines.next().map(str::trim), Some(&input[65..]));
+        assert_eq!(lines.next(), Some(&*format!("  {}^^^^^^", " ".repeat(32))));
+    }
+
+    #[test]
+    fn source() {
+        use core::error::Error as _;
+
+        let err = Error::from(parse_error(":museun!museun PRIVMSG #museun :hello"));
+        let source = err.source().and_then(|s| s.downcast_ref::<ParseError>());
+        assert_eq!(
+            source.map(ParseError::reason),
+            Some(ParseErrorReason::MalformedPrefix)
+        );
+
+        assert!(Error::InvalidUtf8 { offset: 0 }.source().is_none());
     }
 }
@@ -9,7 +9,7 @@
 //! |CR|\r|
 //! |LF|\n|
 //! |all others|the character itself|
-use std::borrow::Cow;
+use alloc::{borrow::Cow, string::String};
 
 /// Estimate how much extra bytes you'll need if you escaped this string
 pub fn estimate_escape_size(input: &str) -> usize {
@@ -0,0 +1,219 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{
+    cheermotes::parse_cheermote, known_tags::KnownTag, messages::UserNameRef, parse_emotes, Emote,
+    Tags,
+};
+
+/// A run of a message, see [`Privmsg::fragments`](crate::messages::Privmsg::fragments)
+///
+/// The fragments of a message cover all of its text, in order, with no gaps.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Fragment<'a> {
+    /// Plain text, including any whitespace
+    Text(&'a str),
+    /// An emote
+    Emote(Emote<'a>),
+    /// A mention of a user (e.g. `@museun`), without the `@`
+    Mention(&'a UserNameRef),
+    /// A cheermote (e.g. `Cheer100`)
+    Cheer {
+        /// The prefix of the cheermote, as it was written in the message
+        prefix: &'a str,
+        /// The amount of bits cheered with this cheermote
+        amount: u64,
+    },
+    /// A link, this is its byte range in the message data
+    Url(Range<usize>),
+}
+
+/// Split `data` into [`Fragment`]s
+///
+/// - emotes come from the `emotes` tag, emotes that overlap an earlier emote or are invalid are treated as text
+/// - cheermotes are only looked for if `cheer_prefixes` is provided
+/// - if `data` is an `ACTION`, the fragments only cover the text of the action
+pub(crate) fn fragments<'a>(
+    tags: &'a Tags<'_>,
+    data: &'a str,
+    cheer_prefixes: Option<&[&str]>,
+) -> Vec<Fragment<'a>> {
+    // emote positions are relative to the text of an action
+    let body = data
+        .strip_prefix("\u{1}ACTION ")
+        .and_then(|s| s.strip_suffix('\u{1}'))
+        .unwrap_or(data);
+    let offset = body.as_ptr() as usize - data.as_ptr() as usize;
+
+    let mut emotes = tags
+        .get(KnownTag::Emotes)
+        .into_iter()
+        .flat_map(|emotes| parse_emotes(emotes, body))
+        .map(|mut emote| {
+            emote.byte_pos = (emote.byte_pos.0 + offset, emote.byte_pos.1 + offset);
+            emote
+        })
+        .collect::<Vec<_>>();
+    emotes.sort_by_key(|emote| emote.byte_pos);
+
+    let mut out = Vec::new();
+    let mut pos = offset;
+    for emote in emotes {
+        let (start, end) = emote.byte_pos;
+        if start < pos {
+            continue;
+        }
+        split_text(data, pos..start, cheer_prefixes, &mut out);
+        out.push(Fragment::Emote(emote));
+        pos = end;
+    }
+    split_text(data, pos..offset + body.len(), cheer_prefixes, &mut out);
+    out
+}
+
+fn split_text<'a>(
+    data: &'a str,
+    range: Range<usize>,
+    cheer_prefixes: Option<&[&str]>,
+    out: &mut Vec<Fragment<'a>>,
+) {
+    let mut text_start = range.start;
+    for word in data[range.clone()].split(|c: char| c.is_ascii_whitespace()) {
+        let start = word.as_ptr() as usize - data.as_ptr() as usize;
+        let Some((fragment, len)) = classify(word, start, cheer_prefixes) else {
+            continue;
+        };
+        if text_start < start {
+            out.push(Fragment::Text(&data[text_start..start]));
+        }
+        out.push(fragment);
+        text_start = start + len;
+    }
+
+    if text_start < range.end {
+        out.push(Fragment::Text(&data[text_start..range.end]));
+    }
+}
+
+/// Classify a word starting at `start`, returning the fragment and how much of the word it covers
+fn classify<'a>(
+    word: &'a str,
+    start: usize,
+    cheer_prefixes: Option<&[&str]>,
+) -> Option<(Fragment<'a>, usize)> {
+    if let Some(login) = word.strip_prefix('@') {
+        let len = login
+            .bytes()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == b'_')
+            .count();
+        return (len > 0).then(|| (Fragment::Mention(login[..len].into()), len + 1));
+    }
+
+    let is_url = ["http://", "https://"].iter().any(|scheme| {
+        word.len() > scheme.len()
+            && word
+                .get(..scheme.len())
+                .is_some_and(|s| s.eq_ignore_ascii_case(scheme))
+    });
+    if is_url {
+        // trailing punctuation is probably not part of the link
+        let len = word
+            .trim_end_matches(['.', ',', '!', '?', ';', ':', ')', '"', '\''])
+            .len();
+        return Some((Fragment::Url(start..start + len), len));
+    }
+
+    let (prefix, amount) = parse_cheermote(word, cheer_prefixes?)?;
+    Some((Fragment::Cheer { prefix, amount }, word.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parse as _;
+    use alloc::{borrow::Cow, string::String};
+
+    fn join(data: &str, fragments: &[Fragment<'_>]) -> String {
+        let mut out = String::new();
+        for fragment in fragments {
+            match fragment {
+                Fragment::Text(text) => out.push_str(text),
+                Fragment::Emote(emote) => out.push_str(&emote.name),
+                Fragment::Mention(login) => {
+                    out.push('@');
+                    out.push_str(login.as_str())
+                }
+                Fragment::Cheer { prefix, amount } => {
+                    out.push_str(prefix);
+                    out.push_str(&alloc::format!("{amount}"))
+                }
+                Fragment::Url(range) => out.push_str(&data[range.clone()]),
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn fragments() {
+        let tags = Tags::parse(&mut "@emotes=25:0-4,50-54 ").unwrap();
+        let data = "Kappa hi @museun, see https://twitch.tv. Cheer100 Kappa";
+
+        let fragments = super::fragments(&tags, data, Some(&["Cheer"]));
+        assert_eq!(
+            fragments,
+            [
+                Fragment::Emote(Emote {
+                    id: Cow::Borrowed("25".into()),
+                    name: Cow::Borrowed("Kappa"),
+                    byte_pos: (0, 5)
+                }),
+                Fragment::Text(" hi "),
+                Fragment::Mention("museun".into()),
+                Fragment::Text(", see "),
+                Fragment::Url(22..39),
+                Fragment::Text(". "),
+                Fragment::Cheer {
+                    prefix: "Cheer",
+                    amount: 100
+                },
+                Fragment::Text(" "),
+                Fragment::Emote(Emote {
+                    id: Cow::Borrowed("25".into()),
+                    name: Cow::Borrowed("Kappa"),
+                    byte_pos: (50, 55)
+                }),
+            ]
+        );
+        assert_eq!(join(data, &fragments), data);
+
+        // no cheers without prefixes
+        let fragments = super::fragments(&tags, data, None);
+        assert_eq!(fragments[5], Fragment::Text(". Cheer100 "));
+        assert_eq!(join(data, &fragments), data);
+    }
+
+    #[test]
+    fn bad_emotes() {
+        // overlapping and out of range emotes
+        let tags = Tags::parse(&mut "@emotes=25:0-4,2-6,40-44 ").unwrap();
+        let data = "Kappa \u{1F600} Kappa";
+
+        let fragments = super::fragments(&tags, data, None);
+        assert_eq!(fragments.len(), 2);
+        assert!(matches!(&fragments[0], Fragment::Emote(emote) if emote.byte_pos == (0, 5)));
+        assert_eq!(join(data, &fragments), data);
+    }
+
+    #[test]
+    fn action() {
+        let tags = Tags::parse(&mut "@emotes=25:5-9 ").unwrap();
+        let data = "\u{1}ACTION hugs Kappa\u{1}";
+
+        let fragments = super::fragments(&tags, data, None);
+        assert_eq!(fragments.len(), 2);
+        assert_eq!(fragments[0], Fragment::Text("hugs "));
+        assert!(
+            matches!(&fragments[1], Fragment::Emote(emote) if &data[emote.byte_pos.0..emote.byte_pos.1] == "Kappa")
+        );
+    }
+}
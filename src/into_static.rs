@@ -1,6 +1,6 @@
-use std::borrow::Cow;
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
 
-use crate::{messages::*, Badge, HashMap, Prefix, Tags};
+use crate::{messages::*, Badge, KnownBadge, Prefix, Tags};
 
 /// A trait for converting a T: 'a to a T: 'static
 ///
@@ -15,11 +15,6 @@ pub trait IntoStatic {
     fn into_static(self) -> Self::Output;
 }
 
-mod private {
-    pub trait Sealed {}
-    impl<T> Sealed for T where T: super::IntoStatic {}
-}
-
 impl<'a> IntoStatic for Cow<'a, str> {
     type Output = Cow<'static, str>;
 
@@ -31,7 +26,7 @@ impl<'a> IntoStatic for Cow<'a, str> {
     }
 }
 
-impl<'a, T> IntoStatic for &'a T
+impl<T> IntoStatic for &T
 where
     T: IntoStatic,
     T: Clone,
@@ -126,13 +121,14 @@ where
     }
 }
 
+#[cfg(any(feature = "std", feature = "hashbrown"))]
 impl<K, V> IntoStatic for crate::HashMap<K, V>
 where
     K: IntoStatic,
     V: IntoStatic,
-    K::Output: Eq + std::hash::Hash,
+    K::Output: Eq + core::hash::Hash,
 {
-    type Output = HashMap<K::Output, V::Output>;
+    type Output = crate::HashMap<K::Output, V::Output>;
 
     fn into_static(self) -> Self::Output {
         self.into_iter()
@@ -179,6 +175,8 @@ where
             Self::ClearMsg(msg) => TwitchMessage::ClearMsg(msg.into_static()),
             Self::Capability(msg) => TwitchMessage::Capability(msg.into_static()),
             Self::ClearChat(msg) => TwitchMessage::ClearChat(msg.into_static()),
+            Self::Names(msg) => TwitchMessage::Names(msg.into_static()),
+            Self::EndOfNames(msg) => TwitchMessage::EndOfNames(msg.into_static()),
             Self::Message(msg) => TwitchMessage::Message(msg.into_static()),
         }
     }
@@ -210,7 +208,11 @@ where
 
     fn into_static(self) -> Self::Output {
         Tags {
-            inner: self.inner.into_static(),
+            inner: self
+                .inner
+                .into_iter()
+                .map(crate::tags::Tag::into_static)
+                .collect(),
         }
     }
 }
@@ -262,6 +264,14 @@ where
             Self::UserNotice => MessageKind::UserNotice,
             Self::Whisper => MessageKind::Whisper,
             Self::Reconnect => MessageKind::Reconnect,
+            Self::Names => MessageKind::Names,
+            Self::EndOfNames => MessageKind::EndOfNames,
+            Self::YourHost => MessageKind::YourHost,
+            Self::Created => MessageKind::Created,
+            Self::MyInfo => MessageKind::MyInfo,
+            Self::Motd => MessageKind::Motd,
+            Self::MotdStart => MessageKind::MotdStart,
+            Self::UnknownCommand => MessageKind::UnknownCommand,
             Self::Numeric(n) => MessageKind::Numeric(n),
             Self::Unknown(s) => MessageKind::Unknown(IntoStatic::into_static(s)),
         }
@@ -297,6 +307,33 @@ where
     }
 }
 
+impl<'a> IntoStatic for ChatClear<'a>
+where
+    'static: 'a,
+{
+    type Output = ChatClear<'static>;
+    fn into_static(self) -> Self::Output {
+        match self {
+            Self::All => ChatClear::All,
+            Self::Ban { user, user_id } => ChatClear::Ban {
+                user: user.into_static(),
+                user_id: user_id.into_static(),
+            },
+            Self::Timeout {
+                user,
+                user_id,
+                duration,
+                expires_at,
+            } => ChatClear::Timeout {
+                user: user.into_static(),
+                user_id: user_id.into_static(),
+                duration,
+                expires_at,
+            },
+        }
+    }
+}
+
 impl<'a> IntoStatic for ClearChatTarget<'a>
 where
     'static: 'a,
@@ -446,6 +483,35 @@ where
     }
 }
 
+impl<'a> IntoStatic for Names<'a>
+where
+    'static: 'a,
+{
+    type Output = Names<'static>;
+    fn into_static(self) -> Self::Output {
+        Self::Output {
+            name: self.name.into_static(),
+            channel: self.channel.into_static(),
+            names: self.names.into_static(),
+            raw: self.raw.into_static(),
+        }
+    }
+}
+
+impl<'a> IntoStatic for EndOfNames<'a>
+where
+    'static: 'a,
+{
+    type Output = EndOfNames<'static>;
+    fn into_static(self) -> Self::Output {
+        Self::Output {
+            name: self.name.into_static(),
+            channel: self.channel.into_static(),
+            raw: self.raw.into_static(),
+        }
+    }
+}
+
 impl<'a> IntoStatic for Reconnect<'a>
 where
     'static: 'a,
@@ -501,6 +567,42 @@ where
     }
 }
 
+impl<'a> IntoStatic for UserNoticeId<'a>
+where
+    'static: 'a,
+{
+    type Output = UserNoticeId<'static>;
+    #[allow(deprecated)]
+    fn into_static(self) -> Self::Output {
+        match self {
+            Self::Sub => UserNoticeId::Sub,
+            Self::Resub => UserNoticeId::Resub,
+            Self::Subgift => UserNoticeId::Subgift,
+            Self::AnonSubgift => UserNoticeId::AnonSubgift,
+            Self::SubMysteryGift => UserNoticeId::SubMysteryGift,
+            Self::AnonSubMysteryGift => UserNoticeId::AnonSubMysteryGift,
+            Self::GiftPaidUpgrade => UserNoticeId::GiftPaidUpgrade,
+            Self::AnonGiftPaidUpgrade => UserNoticeId::AnonGiftPaidUpgrade,
+            Self::PrimePaidUpgrade => UserNoticeId::PrimePaidUpgrade,
+            Self::PrimeCommunityGiftReceived => UserNoticeId::PrimeCommunityGiftReceived,
+            Self::ExtendSub => UserNoticeId::ExtendSub,
+            Self::StandardPayForward => UserNoticeId::StandardPayForward,
+            Self::CommunityPayForward => UserNoticeId::CommunityPayForward,
+            Self::RewardGift => UserNoticeId::RewardGift,
+            Self::Raid => UserNoticeId::Raid,
+            Self::Unraid => UserNoticeId::Unraid,
+            Self::Ritual => UserNoticeId::Ritual,
+            Self::BitsBadgeTier => UserNoticeId::BitsBadgeTier,
+            Self::Announcement => UserNoticeId::Announcement,
+            Self::ViewerMilestone => UserNoticeId::ViewerMilestone,
+            Self::CharityDonation => UserNoticeId::CharityDonation,
+            Self::OneTapGiftRedeemed => UserNoticeId::OneTapGiftRedeemed,
+            Self::SharedChatNotice => UserNoticeId::SharedChatNotice,
+            Self::Other(id) => UserNoticeId::Other(id.into_static()),
+        }
+    }
+}
+
 impl<'a> IntoStatic for Whisper<'a>
 where
     'static: 'a,
@@ -539,6 +641,51 @@ impl<'a> IntoStatic for Part<'a> {
     }
 }
 
+impl<'a> IntoStatic for SubPlan<'a> {
+    type Output = SubPlan<'static>;
+
+    fn into_static(self) -> Self::Output {
+        match self {
+            Self::Other(plan) => SubPlan::Other(plan.into_static()),
+            Self::Prime => SubPlan::Prime,
+            Self::Tier1 => SubPlan::Tier1,
+            Self::Tier2 => SubPlan::Tier2,
+            Self::Tier3 => SubPlan::Tier3,
+        }
+    }
+}
+
+impl<'a> IntoStatic for KnownBadge<'a> {
+    type Output = KnownBadge<'static>;
+
+    fn into_static(self) -> Self::Output {
+        match self {
+            Self::Broadcaster => KnownBadge::Broadcaster,
+            Self::Moderator => KnownBadge::Moderator,
+            Self::Vip => KnownBadge::Vip,
+            Self::Subscriber { tier, months } => KnownBadge::Subscriber {
+                tier: tier.into_static(),
+                months,
+            },
+            Self::Founder => KnownBadge::Founder,
+            Self::Staff => KnownBadge::Staff,
+            Self::Admin => KnownBadge::Admin,
+            Self::GlobalMod => KnownBadge::GlobalMod,
+            Self::Partner => KnownBadge::Partner,
+            Self::Artist => KnownBadge::Artist,
+            Self::Bits(amount) => KnownBadge::Bits(amount),
+            Self::BitsLeader(rank) => KnownBadge::BitsLeader(rank),
+            Self::SubGifter(amount) => KnownBadge::SubGifter(amount),
+            Self::SubGiftLeader(rank) => KnownBadge::SubGiftLeader(rank),
+            Self::Premium => KnownBadge::Premium,
+            Self::Turbo => KnownBadge::Turbo,
+            Self::NoAudio => KnownBadge::NoAudio,
+            Self::NoVideo => KnownBadge::NoVideo,
+            Self::Other(badge) => KnownBadge::Other(badge.into_static()),
+        }
+    }
+}
+
 impl<'a> IntoStatic for Badge<'a> {
     type Output = Badge<'static>;
 
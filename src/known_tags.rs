@@ -0,0 +1,203 @@
+//! Well-known [`Tags`](crate::Tags) keys
+//!
+//! These are the [tags documented by Twitch](https://dev.twitch.tv/docs/irc/tags/), along with the messages that carry them.
+//!
+//! [`Tags`](crate::Tags) lookups accept either a `&str` or a [`KnownTag`]:
+//!
+//! ```rust
+//! use twitch_message::{known_tags::KnownTag, messages::Privmsg};
+//!
+//! let input = "@display-name=museun;foo=bar :museun!museun@museun PRIVMSG #museun :hello\r\n";
+//! let pm = twitch_message::parse_as::<Privmsg>(input)?;
+//!
+//! assert_eq!(pm.tags.get(KnownTag::DisplayName), Some("museun"));
+//! assert_eq!(pm.tags.get("display-name"), Some("museun"));
+//!
+//! // and you can find the keys that aren't known
+//! assert_eq!(pm.tags.unknown_keys().collect::<Vec<_>>(), ["foo"]);
+//! # Ok::<(),Box<dyn std::error::Error>>(())
+//! ```
+use crate::messages::MessageKind;
+
+macro_rules! known_tags {
+    ($($(#[$meta:meta])* $ident:ident => $key:literal [$($kind:ident),* $(,)?])*) => {
+        /// A well-known tag key
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[non_exhaustive]
+        pub enum KnownTag {
+            $(
+                #[doc = concat!("`", $key, "`")]
+                $(#[$meta])*
+                $ident,
+            )*
+        }
+
+        impl KnownTag {
+            /// All of the known tags
+            pub const ALL: &'static [Self] = &[$(Self::$ident,)*];
+
+            /// The key of this tag
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$ident => $key,)*
+                }
+            }
+
+            /// Try to find the known tag for this `key`
+            pub fn from_key(key: &str) -> Option<Self> {
+                match key {
+                    $($key => Some(Self::$ident),)*
+                    _ => None,
+                }
+            }
+
+            /// The messages this tag is documented for
+            pub const fn messages(&self) -> &'static [MessageKind<'static>] {
+                match self {
+                    $(Self::$ident => &[$(MessageKind::$kind,)*],)*
+                }
+            }
+        }
+    };
+}
+
+known_tags! {
+    BadgeInfo => "badge-info" [GlobalUserState, Privmsg, UserNotice, UserState]
+    Badges => "badges" [GlobalUserState, Privmsg, UserNotice, UserState, Whisper]
+    BanDuration => "ban-duration" [ClearChat]
+    Bits => "bits" [Privmsg]
+    ClientNonce => "client-nonce" [Privmsg]
+    Color => "color" [GlobalUserState, Privmsg, UserNotice, UserState, Whisper]
+    CustomRewardId => "custom-reward-id" [Privmsg]
+    DisplayName => "display-name" [GlobalUserState, Privmsg, UserNotice, UserState, Whisper]
+    EmoteOnly => "emote-only" [Privmsg, RoomState]
+    EmoteSets => "emote-sets" [GlobalUserState, UserState]
+    Emotes => "emotes" [Privmsg, UserNotice, Whisper]
+    FirstMsg => "first-msg" [Privmsg]
+    Flags => "flags" [Privmsg, UserNotice]
+    FollowersOnly => "followers-only" [RoomState]
+    Id => "id" [Privmsg, UserNotice, UserState]
+    Login => "login" [ClearMsg, UserNotice]
+    MessageId => "message-id" [Whisper]
+    Mod => "mod" [Privmsg, UserNotice, UserState]
+    MsgId => "msg-id" [Notice, Privmsg, UserNotice]
+    R9k => "r9k" [RoomState]
+    ReturningChatter => "returning-chatter" [Privmsg]
+    RoomId => "room-id" [ClearChat, ClearMsg, Privmsg, RoomState, UserNotice]
+    Slow => "slow" [RoomState]
+    SubsOnly => "subs-only" [RoomState]
+    Subscriber => "subscriber" [Privmsg, UserNotice, UserState]
+    SystemMsg => "system-msg" [UserNotice]
+    TargetMsgId => "target-msg-id" [ClearMsg]
+    TargetUserId => "target-user-id" [ClearChat, Notice]
+    ThreadId => "thread-id" [Whisper]
+    TmiSentTs => "tmi-sent-ts" [ClearChat, ClearMsg, Privmsg, UserNotice]
+    Turbo => "turbo" [GlobalUserState, Privmsg, UserNotice, UserState, Whisper]
+    UserId => "user-id" [GlobalUserState, Privmsg, UserNotice, Whisper]
+    UserType => "user-type" [GlobalUserState, Privmsg, UserNotice, UserState, Whisper]
+    Vip => "vip" [Privmsg, UserNotice, UserState]
+
+    ReplyParentMsgId => "reply-parent-msg-id" [Privmsg]
+    ReplyParentUserId => "reply-parent-user-id" [Privmsg]
+    ReplyParentUserLogin => "reply-parent-user-login" [Privmsg]
+    ReplyParentDisplayName => "reply-parent-display-name" [Privmsg]
+    ReplyParentMsgBody => "reply-parent-msg-body" [Privmsg]
+    ReplyThreadParentMsgId => "reply-thread-parent-msg-id" [Privmsg]
+    ReplyThreadParentUserId => "reply-thread-parent-user-id" [Privmsg]
+    ReplyThreadParentUserLogin => "reply-thread-parent-user-login" [Privmsg]
+    ReplyThreadParentDisplayName => "reply-thread-parent-display-name" [Privmsg]
+
+    PinnedChatPaidAmount => "pinned-chat-paid-amount" [Privmsg]
+    PinnedChatPaidCurrency => "pinned-chat-paid-currency" [Privmsg]
+    PinnedChatPaidExponent => "pinned-chat-paid-exponent" [Privmsg]
+    PinnedChatPaidLevel => "pinned-chat-paid-level" [Privmsg]
+    PinnedChatPaidIsSystemMessage => "pinned-chat-paid-is-system-message" [Privmsg]
+
+    SourceBadgeInfo => "source-badge-info" [ClearMsg, Privmsg, UserNotice]
+    SourceBadges => "source-badges" [ClearMsg, Privmsg, UserNotice]
+    SourceId => "source-id" [ClearMsg, Privmsg, UserNotice]
+    SourceMsgId => "source-msg-id" [UserNotice]
+    SourceOnly => "source-only" [Privmsg, UserNotice]
+    SourceRoomId => "source-room-id" [ClearMsg, Privmsg, UserNotice]
+
+    MsgParamBitsSpent => "msg-param-bits-spent" [UserNotice]
+    MsgParamCategory => "msg-param-category" [UserNotice]
+    MsgParamCharityName => "msg-param-charity-name" [UserNotice]
+    MsgParamColor => "msg-param-color" [UserNotice]
+    MsgParamCopoReward => "msg-param-copoReward" [UserNotice]
+    MsgParamCumulativeMonths => "msg-param-cumulative-months" [UserNotice]
+    /// The documented casing of the display name
+    MsgParamDisplayName => "msg-param-displayName" [UserNotice]
+    /// The casing Twitch actually sends for the display name
+    MsgParamDisplayNameAlt => "msg-param-display-name" [UserNotice]
+    MsgParamDomain => "msg-param-domain" [UserNotice]
+    MsgParamDonationAmount => "msg-param-donation-amount" [UserNotice]
+    MsgParamDonationCurrency => "msg-param-donation-currency" [UserNotice]
+    MsgParamExponent => "msg-param-exponent" [UserNotice]
+    MsgParamGiftId => "msg-param-gift-id" [UserNotice]
+    MsgParamGiftMonths => "msg-param-gift-months" [UserNotice]
+    MsgParamLogin => "msg-param-login" [UserNotice]
+    MsgParamMassGiftCount => "msg-param-mass-gift-count" [UserNotice]
+    MsgParamMonths => "msg-param-months" [UserNotice]
+    MsgParamMultimonthDuration => "msg-param-multimonth-duration" [UserNotice]
+    MsgParamMultimonthTenure => "msg-param-multimonth-tenure" [UserNotice]
+    MsgParamOriginId => "msg-param-origin-id" [UserNotice]
+    MsgParamPriorGifterAnonymous => "msg-param-prior-gifter-anonymous" [UserNotice]
+    MsgParamPriorGifterDisplayName => "msg-param-prior-gifter-display-name" [UserNotice]
+    MsgParamPriorGifterId => "msg-param-prior-gifter-id" [UserNotice]
+    MsgParamPriorGifterUserName => "msg-param-prior-gifter-user-name" [UserNotice]
+    MsgParamProfileImageUrl => "msg-param-profileImageURL" [UserNotice]
+    MsgParamPromoGiftTotal => "msg-param-promo-gift-total" [UserNotice]
+    MsgParamPromoName => "msg-param-promo-name" [UserNotice]
+    MsgParamRecipientDisplayName => "msg-param-recipient-display-name" [UserNotice]
+    MsgParamRecipientId => "msg-param-recipient-id" [UserNotice]
+    MsgParamRecipientUserName => "msg-param-recipient-user-name" [UserNotice]
+    MsgParamRitualName => "msg-param-ritual-name" [UserNotice]
+    MsgParamSelectedCount => "msg-param-selected-count" [UserNotice]
+    MsgParamSenderCount => "msg-param-sender-count" [UserNotice]
+    MsgParamSenderLogin => "msg-param-sender-login" [UserNotice]
+    MsgParamSenderName => "msg-param-sender-name" [UserNotice]
+    MsgParamShouldShareStreak => "msg-param-should-share-streak" [UserNotice]
+    MsgParamStreakMonths => "msg-param-streak-months" [UserNotice]
+    MsgParamSubPlan => "msg-param-sub-plan" [UserNotice]
+    MsgParamSubPlanName => "msg-param-sub-plan-name" [UserNotice]
+    MsgParamThreshold => "msg-param-threshold" [UserNotice]
+    MsgParamTotalRewardCount => "msg-param-total-reward-count" [UserNotice]
+    MsgParamTriggerAmount => "msg-param-trigger-amount" [UserNotice]
+    MsgParamTriggerType => "msg-param-trigger-type" [UserNotice]
+    MsgParamValue => "msg-param-value" [UserNotice]
+    MsgParamViewerCount => "msg-param-viewerCount" [UserNotice]
+    MsgParamWasGifted => "msg-param-was-gifted" [UserNotice]
+}
+
+impl AsRef<str> for KnownTag {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> From<KnownTag> for alloc::borrow::Cow<'a, str> {
+    fn from(value: KnownTag) -> Self {
+        Self::Borrowed(value.as_str())
+    }
+}
+
+impl core::fmt::Display for KnownTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for tag in KnownTag::ALL {
+            assert_eq!(KnownTag::from_key(tag.as_str()), Some(*tag));
+            assert!(!tag.messages().is_empty(), "{tag}");
+        }
+        assert_eq!(KnownTag::from_key("foo"), None);
+    }
+}
@@ -53,6 +53,18 @@
 //!
 //! This will return an iterator over possibly many messages in the data
 //!
+//! - [`parse_bytes`], [`parse_many_bytes`] and [`parse_bytes_as`]
+//!
+//! These are the byte-oriented versions of the above, with a [`Utf8Policy`] for handling invalid UTF-8
+//!
+//! - [`Parser`]
+//!
+//! This allows choosing between a [`strict`](ParseMode::Strict) and a [`lenient`](ParseMode::Lenient) parse, and reports any [`Anomaly`] found
+//!
+//! - [`Decoder`]
+//!
+//! This will buffer partial chunks of data (e.g. from a socket), yielding messages once their lines are complete
+//!
 //! - [`parse_as`]
 //!
 //! This is a shorthand for [`parse`](fn@parse) + [`Message::as_typed_message()`](crate::messages::Message::as_typed_message())
@@ -65,6 +77,14 @@
 //!
 //! This allows you to parse ***emotes*** from a Twitch emote string + the associated data portion
 //!
+//! - [`try_parse_emotes`]
+//!
+//! This is like [`parse_emotes`], but reports emotes with bad positions as an [`EmoteError`]
+//!
+//! - [`parse_cheermotes`]
+//!
+//! This allows you to parse ***cheermotes*** (e.g. `Cheer100`) from the data portion of a message
+//!
 //! ---
 //!
 //! # Typed messages
@@ -146,13 +166,29 @@
 //!
 //! | Feature | Description |
 //! | --- | --- |
-//! |default | there are no default features |
-//! |ping | enables the [`PingTracker`] |
-//! |std | enables the [`Encode`](crate::encode::Encode) and [`Encodable`](crate::encode::Encodable) traits |
+//! |default | `std` |
+//! |ping | enables the [`PingTracker`]. this implies `std` |
+//! |std | links to `std`, and enables the [`Encode`](crate::encode::Encode) and [`Encodable`](crate::encode::Encodable) traits. see [`no_std`](#no_std) |
 //! |serde | enables [`serde`] derives on the types |
-//! |hashbrown | enables using [`hashbrown`] for the internal `HashMap` |
+//! |hashbrown | enables using [`hashbrown`] for the `HashMap` (e.g. for [`IntoStatic`] without `std`) |
 //! |sync | enables using [`std::sync::Mutex`] over [`std::cell::RefCell`] see [`sharing data`](#sharing-data) |
 //! |parking_lot | same as `sync` except uses a [`parking_lot::Mutex`] |
+//! |time | enables converting a [`Timestamp`] to a [`time::OffsetDateTime`] |
+//! |chrono | enables converting a [`Timestamp`] to a [`chrono::DateTime`] |
+//! |twitch_types | enables converting the identifiers in [`messages::types`] to and from their [`twitch_types`] equivalents. this implies `std` |
+//!
+//! # no_std
+//!
+//! Disabling the default features builds this crate with `#![no_std]`, using only `core` and `alloc`.
+//!
+//! ```toml
+//! twitch_message = { version = "*", default-features = false }
+//! ```
+//!
+//! Parsing, the typed messages and [`encode`] (with the [`Format`](crate::encode::Format) and [`Formattable`](crate::encode::Formattable) traits) are all available.
+//!
+//! The [`PingTracker`], the [`std::io`] encoders and converting a [`Timestamp`] to a [`SystemTime`](std::time::SystemTime) require `std`.
+//! Without `std`, enable the `hashbrown` feature if you want [`IntoStatic`] for a `HashMap`.
 //!
 //! # Utilities
 //! ## PingTracker
@@ -173,24 +209,35 @@
 //! ---
 //! Twitch chat reference: [`link`](https://dev.twitch.tv/docs/irc/)
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
-#[cfg(not(feature = "hashbrown"))]
+#[cfg(all(feature = "std", not(feature = "hashbrown")))]
 pub(crate) type HashMap<K, V> = std::collections::HashMap<K, V>;
 #[cfg(feature = "hashbrown")]
 pub(crate) type HashMap<K, V> = hashbrown::HashMap<K, V>;
 
 mod error;
-pub use error::Error;
+pub use error::{Error, ParseComponent, ParseError, ParseErrorReason};
 
 mod prefix;
 pub use prefix::Prefix;
 
 mod tags;
-pub use tags::Tags;
+pub use tags::{Tags, TagsIntoIter, TagsIter};
+
+mod tag_key;
+pub use tag_key::TagKey;
+
+pub mod known_tags;
 
 mod color;
 pub use color::Color;
 
+mod timestamp;
+pub use timestamp::Timestamp;
+
 pub mod escape;
 
 mod message_kind;
@@ -203,7 +250,12 @@ use typed_messages::TypedMessageMarker;
 mod message;
 
 mod parse;
-pub use parse::{parse, parse_as, parse_many, Parse, ParseResult};
+mod parser;
+pub use parse::{
+    parse, parse_as, parse_bytes, parse_bytes_as, parse_many, parse_many_bytes, Decoder, Parse,
+    ParseBytesResult, ParseResult, Utf8Policy,
+};
+pub use parser::{Anomaly, ParseMode, Parsed, Parser};
 
 mod into_static;
 pub use into_static::IntoStatic;
@@ -211,10 +263,16 @@ pub use into_static::IntoStatic;
 pub mod encode;
 
 mod badges;
-pub use badges::{parse_badges, Badge, BadgeInfo};
+pub use badges::{parse_badges, Badge, BadgeInfo, KnownBadge};
 
 mod emotes;
-pub use emotes::{parse_emotes, Emote};
+pub use emotes::{parse_emotes, try_parse_emotes, Emote, EmoteError};
+
+mod cheermotes;
+pub use cheermotes::{parse_cheermotes, Cheermote, DEFAULT_CHEERMOTE_PREFIXES};
+
+mod fragments;
+pub use fragments::Fragment;
 
 pub mod builders {
     //! Builders for constructing your own types.
@@ -227,6 +285,7 @@ mod ping_tracker;
 #[cfg(feature = "ping")]
 pub use ping_tracker::PingTracker;
 
+#[cfg(feature = "ping")]
 mod lock;
 
 /// The Twitch IRC (tcp) address
@@ -1,10 +1,18 @@
-use std::borrow::Cow;
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 use crate::{
     encode::octo,
-    messages::{MessageKind, Privmsg, TwitchMessage},
+    known_tags::KnownTag,
+    messages::{MessageKind, PaidMessage, Privmsg, TwitchMessage},
+    parser::{Diagnostics, ParseMode},
     typed_messages::TypedMessageMarker,
-    Error, IntoStatic, Parse, Prefix, Tags,
+    IntoStatic, Parse, ParseComponent, ParseError, ParseErrorReason, Prefix, Tags, Timestamp,
 };
 
 /// A twitch chat message.
@@ -36,6 +44,20 @@ impl<'a> From<Cow<'a, Message<'a>>> for Message<'static> {
     }
 }
 
+impl<'a> Message<'a> {
+    /// When the message was sent, from the `tmi-sent-ts` tag
+    ///
+    /// ```rust
+    /// let data = "@tmi-sent-ts=1669340813893 :museun!museun@museun PRIVMSG #museun :hello world\r\n";
+    /// let message = twitch_message::parse(data)?.message;
+    /// assert_eq!(message.timestamp().map(|ts| ts.as_millis()), Some(1669340813893));
+    /// # Ok::<(),Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.tags.parsed(KnownTag::TmiSentTs)?.ok()
+    }
+}
+
 impl<'a> Message<'a>
 where
     'static: 'a,
@@ -99,6 +121,8 @@ where
             K::UserNotice => convert::<UserNotice<'a>>(this),
             K::Whisper => convert::<Whisper<'a>>(this),
             K::Reconnect => convert::<Reconnect<'a>>(this),
+            K::Names => convert::<Names<'a>>(this),
+            K::EndOfNames => convert::<EndOfNames<'a>>(this),
             _ => TwitchMessage::Message(self.clone()),
         }
     }
@@ -169,45 +193,72 @@ impl Message<'static> {
             K::UserNotice => convert::<UserNotice>(self),
             K::Whisper => convert::<Whisper>(self),
             K::Reconnect => convert::<Reconnect>(self),
+            K::Names => convert::<Names>(self),
+            K::EndOfNames => convert::<EndOfNames>(self),
             _ => self.into(),
         }
     }
 }
 
 impl<'a> Parse<'a> for Message<'a> {
-    type Output = Result<Self, Error>;
+    type Output = Result<Self, ParseError>;
 
     fn parse(input: &mut &'a str) -> Self::Output {
-        fn parse_args<'a>(input: &mut &'a str) -> Vec<Cow<'a, str>> {
+        Self::parse_with(input, &mut Diagnostics::new(input, ParseMode::Lenient))
+    }
+}
+
+impl<'a> Message<'a> {
+    pub(crate) fn parse_with(
+        input: &mut &'a str,
+        diag: &mut Diagnostics<'_>,
+    ) -> Result<Self, ParseError> {
+        fn parse_args<'a>(
+            input: &mut &'a str,
+            diag: &mut Diagnostics<'_>,
+        ) -> Result<Vec<Cow<'a, str>>, ParseError> {
             if let Some(tail) = input.strip_prefix(':') {
                 *input = tail;
-                return vec![];
+                return Ok(vec![]);
             }
 
             if let Some(end) = input.find(" :") {
+                let mut pos = 0;
+                for arg in input[..end].split(' ') {
+                    if arg.is_empty() {
+                        diag.report(
+                            input,
+                            pos..pos,
+                            ParseComponent::Args,
+                            ParseErrorReason::EmptyArgument,
+                        )?;
+                    }
+                    pos += arg.len() + 1;
+                }
+
                 let args = input[..end]
                     .split_ascii_whitespace()
                     .map(Cow::from)
                     .collect();
                 *input = &input[end + 2..];
-                return args;
+                return Ok(args);
             }
 
             let args = vec![Cow::from(*input)];
             *input = "";
-            args
+            Ok(args)
         }
 
         fn parse_data<'a>(input: &mut &'a str) -> Option<Cow<'a, str>> {
-            (!input.is_empty()).then(|| Cow::from(std::mem::take(input)))
+            (!input.is_empty()).then(|| Cow::from(core::mem::take(input)))
         }
 
         Ok(Self {
-            raw: Cow::from(&**input),
-            tags: Tags::parse(input).unwrap_or_default(),
-            prefix: Prefix::parse(input),
-            kind: MessageKind::parse(input)?,
-            args: parse_args(input),
+            raw: Cow::from(*input),
+            tags: Tags::parse_with(input, diag)?,
+            prefix: Prefix::parse_with(input, diag)?,
+            kind: MessageKind::parse_with(input, diag)?,
+            args: parse_args(input, diag)?,
             data: parse_data(input),
         })
     }
@@ -225,8 +276,8 @@ pub enum PrivmsgBuilderError {
     MissingData,
 }
 
-impl std::fmt::Display for PrivmsgBuilderError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for PrivmsgBuilderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::MissingSender => f.write_str("Missing sender"),
             Self::MissingChannel => f.write_str("Missing channel"),
@@ -235,8 +286,8 @@ impl std::fmt::Display for PrivmsgBuilderError {
     }
 }
 
-impl std::error::Error for PrivmsgBuilderError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for PrivmsgBuilderError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         None
     }
 }
@@ -265,6 +316,7 @@ impl std::error::Error for PrivmsgBuilderError {
 #[derive(Default, Debug, Clone)]
 pub struct PrivmsgBuilder {
     tags: Option<Tags<'static>>,
+    paid: Vec<(KnownTag, String)>,
     sender: Option<Cow<'static, str>>,
     channel: Option<Cow<'static, str>>,
     data: Option<Cow<'static, str>>,
@@ -300,6 +352,48 @@ impl PrivmsgBuilder {
         self
     }
 
+    /// Make this a paid (Hype Chat) message, by adding the `pinned-chat-paid-*` tags
+    ///
+    /// These are added to the [`tags`](Self::tags) when the message is finished, replacing any `pinned-chat-paid-*` tags there.
+    ///
+    /// ```rust
+    /// use twitch_message::builders::{PrivmsgBuilder, TagsBuilder};
+    /// use twitch_message::messages::{PaidLevel, PaidMessage};
+    ///
+    /// let paid = PaidMessage {
+    ///     amount: 500,
+    ///     exponent: 2,
+    ///     currency: "USD",
+    ///     level: PaidLevel::Two,
+    ///     is_system_message: false,
+    /// };
+    ///
+    /// let pm = PrivmsgBuilder::default()
+    ///     .channel("museun")
+    ///     .sender("shaken_bot")
+    ///     .data("hello")
+    ///     .paid_message(paid)
+    ///     .tags(TagsBuilder::default().add("color", "#FF0000").finish())
+    ///     .finish_privmsg()?;
+    ///
+    /// assert_eq!(pm.paid_message(), Some(paid));
+    /// assert_eq!(pm.tags.get("color"), Some("#FF0000"));
+    /// # Ok::<(),Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn paid_message(mut self, paid: PaidMessage<'_>) -> Self {
+        self.paid = vec![
+            (KnownTag::PinnedChatPaidAmount, paid.amount.to_string()),
+            (KnownTag::PinnedChatPaidCurrency, paid.currency.to_string()),
+            (KnownTag::PinnedChatPaidExponent, paid.exponent.to_string()),
+            (KnownTag::PinnedChatPaidLevel, paid.level.to_string()),
+            (
+                KnownTag::PinnedChatPaidIsSystemMessage,
+                (paid.is_system_message as u8).to_string(),
+            ),
+        ];
+        self
+    }
+
     /// Construct a [`Privmsg`](crate::messages::Privmsg) from this builder
     pub fn finish_privmsg(self) -> Result<Privmsg<'static>, PrivmsgBuilderError> {
         Ok(self
@@ -310,7 +404,10 @@ impl PrivmsgBuilder {
 
     /// Construct a [`Message`](crate::messages::Message) from this builder
     pub fn finish_message(self) -> Result<Message<'static>, PrivmsgBuilderError> {
-        let tags = self.tags.unwrap_or_default();
+        let mut tags = self.tags.unwrap_or_default();
+        for (key, value) in self.paid {
+            tags.insert(key, value);
+        }
 
         let get =
             |field: Option<Cow<'static, str>>, err| field.filter(|s| !s.is_empty()).ok_or(err);
@@ -1,10 +1,31 @@
-use std::borrow::Cow;
+use alloc::borrow::Cow;
 
-use crate::{Error, Parse};
+use crate::{
+    parser::{Diagnostics, ParseMode},
+    Parse, ParseComponent, ParseError, ParseErrorReason,
+};
 
 /// The kind of the [`Message`](crate::messages::Message)
+///
+/// New kinds may be added, so matches on this need a wildcard arm.
+///
+/// # Breaking change
+///
+/// The `002`, `003`, `004`, `353`, `366`, `372`, `375` and `421` numerics used to be [`MessageKind::Numeric`].
+/// They are now parsed as their own variants, so an arm such as `MessageKind::Numeric(372)` no longer matches them.
+/// Use [`MessageKind::numeric`] to match on any numeric by its number:
+///
+/// ```rust
+/// use twitch_message::messages::MessageKind;
+///
+/// let msg = twitch_message::parse(":tmi.twitch.tv 372 museun :-\r\n")?.message;
+/// assert_eq!(msg.kind, MessageKind::Motd);
+/// assert_eq!(msg.kind.numeric(), Some(372));
+/// # Ok::<(),Box<dyn std::error::Error>>(())
+/// ```
 #[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[non_exhaustive]
 pub enum MessageKind<'a> {
     /// [`Capability`](super::messages::Capability)
     Capability,
@@ -42,6 +63,22 @@ pub enum MessageKind<'a> {
     Whisper,
     /// [`Reconnect`](super::messages::Reconnect)
     Reconnect,
+    /// [`Names`](super::messages::Names) (`353`)
+    Names,
+    /// [`EndOfNames`](super::messages::EndOfNames) (`366`)
+    EndOfNames,
+    /// The `002` (`RPL_YOURHOST`) numeric
+    YourHost,
+    /// The `003` (`RPL_CREATED`) numeric
+    Created,
+    /// The `004` (`RPL_MYINFO`) numeric
+    MyInfo,
+    /// The `372` (`RPL_MOTD`) numeric
+    Motd,
+    /// The `375` (`RPL_MOTDSTART`) numeric
+    MotdStart,
+    /// The `421` (`ERR_UNKNOWNCOMMAND`) numeric, sent when Twitch doesn't support a command
+    UnknownCommand,
     /// IRC Numeric
     Numeric(u16),
     /// An unknown message kind
@@ -49,6 +86,24 @@ pub enum MessageKind<'a> {
 }
 
 impl MessageKind<'_> {
+    /// The IRC numeric of this kind, if it was sent as one
+    pub const fn numeric(&self) -> Option<u16> {
+        Some(match self {
+            Self::IrcReady => 1,
+            Self::YourHost => 2,
+            Self::Created => 3,
+            Self::MyInfo => 4,
+            Self::Names => 353,
+            Self::EndOfNames => 366,
+            Self::Motd => 372,
+            Self::MotdStart => 375,
+            Self::Ready => 376,
+            Self::UnknownCommand => 421,
+            Self::Numeric(numeric) => *numeric,
+            _ => return None,
+        })
+    }
+
     pub(crate) const fn as_str(&self) -> &'static str {
         match self {
             Self::Capability => "Capability",
@@ -69,6 +124,14 @@ impl MessageKind<'_> {
             Self::UserNotice => "UserNotice",
             Self::Whisper => "Whisper",
             Self::Reconnect => "Reconnect",
+            Self::Names => "Names",
+            Self::EndOfNames => "EndOfNames",
+            Self::YourHost => "YourHost",
+            Self::Created => "Created",
+            Self::MyInfo => "MyInfo",
+            Self::Motd => "Motd",
+            Self::MotdStart => "MotdStart",
+            Self::UnknownCommand => "UnknownCommand",
             Self::Numeric(_) => "Numeric",
             Self::Unknown(_) => "Unknown",
         }
@@ -76,9 +139,21 @@ impl MessageKind<'_> {
 }
 
 impl<'a> Parse<'a> for MessageKind<'a> {
-    type Output = Result<Self, Error>;
+    type Output = Result<Self, ParseError>;
 
     fn parse(input: &mut &'a str) -> Self::Output {
+        Self::parse_with(input, &mut Diagnostics::new(input, ParseMode::Lenient))
+    }
+}
+
+impl<'a> MessageKind<'a> {
+    pub(crate) fn parse_with(
+        input: &mut &'a str,
+        diag: &mut Diagnostics<'_>,
+    ) -> Result<Self, ParseError> {
+        let error = |input, span, reason| diag.error(input, span, ParseComponent::Command, reason);
+
+        let line = *input;
         let head = match input.split_once(' ') {
             Some((head, tail)) => {
                 *input = tail;
@@ -91,6 +166,10 @@ impl<'a> Parse<'a> for MessageKind<'a> {
             }
         };
 
+        if head.is_empty() {
+            return Err(error(line, 0..0, ParseErrorReason::MissingCommand));
+        }
+
         let kind = match head {
             "CAP" => Self::Capability,
 
@@ -98,7 +177,15 @@ impl<'a> Parse<'a> for MessageKind<'a> {
             "PONG" => Self::Pong,
 
             "001" => Self::IrcReady,
+            "002" => Self::YourHost,
+            "003" => Self::Created,
+            "004" => Self::MyInfo,
+            "353" => Self::Names,
+            "366" => Self::EndOfNames,
+            "372" => Self::Motd,
+            "375" => Self::MotdStart,
             "376" => Self::Ready,
+            "421" => Self::UnknownCommand,
 
             "GLOBALUSERSTATE" => Self::GlobalUserState,
             "USERSTATE" => Self::UserState,
@@ -120,11 +207,50 @@ impl<'a> Parse<'a> for MessageKind<'a> {
             "JOIN" => Self::Join,
             "PART" => Self::Part,
 
-            s if s.chars().all(|c| c.is_ascii_digit()) => {
-                Self::Numeric(s.parse().map_err(|_| Error::InvalidNumeric)?)
-            }
+            s if s.chars().all(|c| c.is_ascii_digit()) => Self::Numeric(
+                s.parse()
+                    .map_err(|_| error(line, 0..s.len(), ParseErrorReason::InvalidNumeric))?,
+            ),
             unknown => Self::Unknown(Cow::from(unknown)),
         };
         Ok(kind)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numerics() {
+        for (input, kind) in [
+            (
+                ":tmi.twitch.tv 002 museun :Your host is tmi.twitch.tv\r\n",
+                MessageKind::YourHost,
+            ),
+            (
+                ":tmi.twitch.tv 003 museun :This server is rather new\r\n",
+                MessageKind::Created,
+            ),
+            (":tmi.twitch.tv 004 museun :-\r\n", MessageKind::MyInfo),
+            (":tmi.twitch.tv 375 museun :-\r\n", MessageKind::MotdStart),
+            (
+                ":tmi.twitch.tv 372 museun :You are in a maze of twisty passages, all alike.\r\n",
+                MessageKind::Motd,
+            ),
+            (
+                ":tmi.twitch.tv 421 museun WHO :Unknown command\r\n",
+                MessageKind::UnknownCommand,
+            ),
+            (
+                ":tmi.twitch.tv 999 museun :?\r\n",
+                MessageKind::Numeric(999),
+            ),
+        ] {
+            let parsed = crate::parse(input).unwrap().message.kind;
+            assert_eq!(parsed, kind, "{input}");
+            assert_eq!(parsed.numeric(), input[15..18].parse().ok(), "{input}");
+        }
+        assert_eq!(MessageKind::Privmsg.numeric(), None);
+    }
+}
@@ -3,6 +3,75 @@ use super::{Prefix, Tags};
 
 pub use super::{message::Message, message_kind::MessageKind};
 
+// role helpers for the messages that have `badges`, `$subject` starts the doc comments
+macro_rules! role_helpers {
+    ($($ty:ident => $subject:literal)*) => {
+        $(
+            impl<'a> $ty<'a> {
+                /// The [`badges`](Self::badges) as [`KnownBadge`](crate::KnownBadge)s
+                pub fn known_badges<'t: 'a>(
+                    &'t self,
+                ) -> impl Iterator<Item = crate::KnownBadge<'a>> + 't {
+                    self.badges().map(crate::KnownBadge::from)
+                }
+
+                /// How many months the user has been subscribed to the channel
+                ///
+                /// This uses the `badge-info` tag, falling back to the tenure of the `subscriber` badge.
+                /// A `founder` badge without the `badge-info` tag counts as a single month
+                pub fn subscriber_months(&self) -> Option<u32> {
+                    crate::badges::subscriber_months(&self.tags)
+                }
+
+                #[doc = concat!($subject, " the broadcaster of the channel")]
+                pub fn is_from_broadcaster(&self) -> bool {
+                    self.has_badge("broadcaster")
+                }
+
+                #[doc = concat!($subject, " a moderator in the channel")]
+                pub fn is_from_moderator(&self) -> bool {
+                    self.has_badge("moderator")
+                }
+
+                #[doc = concat!($subject, " a VIP in the channel")]
+                pub fn is_from_vip(&self) -> bool {
+                    self.has_badge("vip")
+                }
+
+                #[doc = concat!($subject, " a subscriber (or a founder) of the channel")]
+                pub fn is_from_subscriber(&self) -> bool {
+                    self.has_badge("subscriber") || self.has_badge("founder")
+                }
+
+                #[doc = concat!($subject, " Twitch staff")]
+                pub fn is_from_staff(&self) -> bool {
+                    self.has_badge("staff")
+                }
+
+                #[doc = concat!($subject, " a turbo user")]
+                pub fn is_from_turbo(&self) -> bool {
+                    self.has_badge("turbo")
+                }
+
+                #[doc = concat!($subject, " a global moderator")]
+                pub fn is_from_global_moderator(&self) -> bool {
+                    self.has_badge("global_mod")
+                }
+
+                #[doc = concat!($subject, " an admin")]
+                pub fn is_from_admin(&self) -> bool {
+                    self.has_badge("admin")
+                }
+
+                // this matches on the name, so a badge with an unexpected version still counts
+                fn has_badge(&self, name: &str) -> bool {
+                    self.badges().any(|badge| badge.name.as_str() == name)
+                }
+            }
+        )*
+    };
+}
+
 mod capability;
 pub use capability::Capability;
 
@@ -25,20 +94,20 @@ mod user_state;
 pub use user_state::UserState;
 
 mod room_state;
-pub use room_state::RoomState;
+pub use room_state::{RoomMode, RoomModes, RoomState};
 
 mod privmsg;
-pub use privmsg::Privmsg;
+pub use privmsg::{PaidLevel, PaidMessage, Privmsg, ReplyInfo, ReplyMessage};
 
 mod clear_chat;
-pub use clear_chat::{ClearChat, ClearChatTarget};
+pub use clear_chat::{ChatClear, ClearChat, ClearChatTarget};
 
 mod clear_msg;
 pub use clear_msg::ClearMsg;
 
 mod notice;
 #[allow(deprecated)]
-pub use notice::{Notice, NoticeKind};
+pub use notice::{Notice, NoticeCategory, NoticeKind, NoticeOutcome};
 
 mod host_target;
 #[allow(deprecated)]
@@ -47,22 +116,47 @@ pub use host_target::{HostMode, HostTarget};
 mod whisper;
 pub use whisper::Whisper;
 
+mod names;
+pub use names::{EndOfNames, Names};
+
+mod shared_chat;
+pub use shared_chat::SharedChatOrigin;
+
 mod reconnect;
 pub use reconnect::Reconnect;
 
 mod user_notice;
 pub use user_notice::{UserNotice, UserNoticeId};
 
+mod sub_plan;
+pub use sub_plan::SubPlan;
+
+mod user_notice_event;
+pub use user_notice_event::{
+    AnnouncementColor, EventUser, MysteryGift, Promo, SubGift, Subscription, UserNoticeEvent,
+    UserNoticeEventError,
+};
+
 mod join;
 pub use join::Join;
 
 mod part;
 pub use part::Part;
 
+role_helpers! {
+    Privmsg => "The message is from"
+    UserState => "The user is"
+    GlobalUserState => "The user is"
+    Whisper => "The whisper is from"
+    UserNotice => "The notice is from"
+}
+
 mod twitch_message;
 pub use self::twitch_message::TwitchMessage;
 
-pub use twitch_types::{
-    self as types, BadgeSetIdRef, ChatBadgeIdRef, DisplayNameRef, EmoteIdRef, EmoteSetIdRef,
-    IntoCow, MsgIdRef, UserIdRef, UserNameRef, UserType,
+pub mod types;
+
+pub use types::{
+    BadgeSetIdRef, ChatBadgeIdRef, DisplayNameRef, EmoteIdRef, EmoteSetIdRef, IntoCow, MsgIdRef,
+    UserIdRef, UserNameRef, UserType,
 };
@@ -1,6 +1,8 @@
-use std::borrow::Cow;
+use crate::{known_tags::KnownTag, Timestamp};
+use alloc::borrow::Cow;
+use core::time::Duration;
 
-use super::{IntoCow, Message, Tags};
+use super::{IntoCow, Message, Tags, UserIdRef, UserNameRef};
 
 /// [`CLEARCHAT`](https://dev.twitch.tv/docs/irc/commands/#clearchat) command. Sent when a bot or moderator removes all messages from the chat room or removes all messages for the specified user.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,22 +21,74 @@ pub struct ClearChat<'a> {
 impl<'a> ClearChat<'a> {
     /// The duration in seconds the user has been timed out for. Is [`None`] if the command targets all chat messages.
     pub fn ban_duration(&self) -> Option<usize> {
-        self.tags.parsed("ban-duration").transpose().ok().flatten()
+        self.tags
+            .parsed(KnownTag::BanDuration)
+            .transpose()
+            .ok()
+            .flatten()
     }
 
     /// The ID of the channel where the messages were removed from.
     pub fn room_id(&self) -> Option<&super::UserIdRef> {
-        self.tags.get("room-id").map(Into::into)
+        self.tags.get(KnownTag::RoomId).map(Into::into)
     }
 
     /// The ID of the user that was banned or put in a timeout. The user was banned if the message doesn’t include the ban-duration tag.
     pub fn target_user_id(&self) -> Option<&super::UserIdRef> {
-        self.tags.get("target-user-id").map(Into::into)
+        self.tags.get(KnownTag::TargetUserId).map(Into::into)
     }
 
     /// The UNIX timestamp.
+    ///
+    /// See [`timestamp`](Self::timestamp) for a parsed version
     pub fn tmi_sent_ts(&self) -> Option<&str> {
-        self.tags.get("tmi-sent-ts")
+        self.tags.get(KnownTag::TmiSentTs)
+    }
+
+    /// When the message was sent, from the [`tmi_sent_ts`](Self::tmi_sent_ts)
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.tags.parsed(KnownTag::TmiSentTs)?.ok()
+    }
+
+    /// What this `CLEARCHAT` did: cleared the chat, banned a user or timed out a user
+    ///
+    /// A user was banned if the message doesn’t include the `ban-duration` tag.
+    /// If the tag is present but isn't a number of seconds, the user was timed out for an unknown duration.
+    ///
+    /// ```rust
+    /// # use twitch_message::{messages::{ChatClear, ClearChat}, Timestamp};
+    /// # use std::time::Duration;
+    /// let input = "@ban-duration=600;room-id=12345678;target-user-id=87654321;tmi-sent-ts=1642715756806 :tmi.twitch.tv CLEARCHAT #museun :ronni\r\n";
+    /// let clear_chat = twitch_message::parse_as::<ClearChat>(input)?;
+    ///
+    /// let ChatClear::Timeout { user, duration, expires_at, .. } = clear_chat.action() else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(user.as_str(), "ronni");
+    /// assert_eq!(duration, Some(Duration::from_secs(600)));
+    /// assert_eq!(expires_at, Some(Timestamp::from_millis(1642716356806)));
+    ///
+    /// assert_eq!(clear_chat.action().to_string(), "ronni was timed out for 600 seconds");
+    /// # Ok::<(),Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn action(&self) -> ChatClear<'_> {
+        let user = match &self.target {
+            ClearChatTarget::All => return ChatClear::All,
+            ClearChatTarget::User(user) => Cow::Borrowed(&**user),
+        };
+        let user_id = self.target_user_id().map(Cow::Borrowed);
+
+        let Some(duration) = self.tags.get_raw(KnownTag::BanDuration) else {
+            return ChatClear::Ban { user, user_id };
+        };
+
+        let duration = duration.parse().ok().map(Duration::from_secs);
+        ChatClear::Timeout {
+            user,
+            user_id,
+            duration,
+            expires_at: self.timestamp().zip(duration).map(|(ts, d)| ts + d),
+        }
     }
 }
 
@@ -54,6 +108,70 @@ pub enum ClearChatTarget<'a> {
     User(Cow<'a, super::UserNameRef>),
 }
 
+/// The action a [`ClearChat`] represents, see [`ClearChat::action`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum ChatClear<'a> {
+    /// All chat messages were removed
+    All,
+    /// A user was permanently banned
+    Ban {
+        /// The login of the banned user
+        user: Cow<'a, UserNameRef>,
+        /// The ID of the banned user
+        user_id: Option<Cow<'a, UserIdRef>>,
+    },
+    /// A user was put in a timeout
+    Timeout {
+        /// The login of the user
+        user: Cow<'a, UserNameRef>,
+        /// The ID of the user
+        user_id: Option<Cow<'a, UserIdRef>>,
+        /// How long the user was timed out for, [`None`] if the `ban-duration` tag was invalid
+        duration: Option<Duration>,
+        /// When the timeout ends, if the message had a `tmi-sent-ts` and a valid `ban-duration`
+        expires_at: Option<Timestamp>,
+    },
+}
+
+impl<'a> ChatClear<'a> {
+    /// The login of the user this targets. This is [`None`] if all chat messages were removed
+    pub fn user(&self) -> Option<&UserNameRef> {
+        match self {
+            Self::All => None,
+            Self::Ban { user, .. } | Self::Timeout { user, .. } => Some(user),
+        }
+    }
+
+    /// The ID of the user this targets, if it was provided
+    pub fn user_id(&self) -> Option<&UserIdRef> {
+        match self {
+            Self::All => None,
+            Self::Ban { user_id, .. } | Self::Timeout { user_id, .. } => user_id.as_deref(),
+        }
+    }
+}
+
+impl<'a> core::fmt::Display for ChatClear<'a> {
+    /// A short description of the action, for logging
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::All => f.write_str("chat was cleared"),
+            Self::Ban { user, .. } => write!(f, "{user} was banned"),
+            Self::Timeout {
+                user,
+                duration: Some(duration),
+                ..
+            } => write!(
+                f,
+                "{user} was timed out for {secs} seconds",
+                secs = duration.as_secs()
+            ),
+            Self::Timeout { user, .. } => write!(f, "{user} was timed out"),
+        }
+    }
+}
+
 impl<'a> TryFrom<Message<'a>> for ClearChat<'a> {
     type Error = Message<'a>;
 
@@ -132,4 +250,57 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn action() {
+        let input =
+            "@room-id=12345678;tmi-sent-ts=1642715695392 :tmi.twitch.tv CLEARCHAT #museun\r\n";
+        let clear_chat = test_util::parse_as::<ClearChat>(input);
+        assert_eq!(clear_chat.action(), ChatClear::All);
+        assert_eq!(clear_chat.action().user(), None);
+
+        let input = "@room-id=12345678;target-user-id=87654321;tmi-sent-ts=1642715756806 :tmi.twitch.tv CLEARCHAT #museun :ronni\r\n";
+        let clear_chat = test_util::parse_as::<ClearChat>(input);
+        assert_eq!(
+            clear_chat.action(),
+            ChatClear::Ban {
+                user: IntoCow::into_cow("ronni"),
+                user_id: Some(IntoCow::into_cow("87654321")),
+            }
+        );
+        assert_eq!(clear_chat.action().to_string(), "ronni was banned");
+
+        let input = "@ban-duration=350;room-id=12345678;target-user-id=87654321 :tmi.twitch.tv CLEARCHAT #museun :ronni\r\n";
+        let clear_chat = test_util::parse_as::<ClearChat>(input);
+        let action = clear_chat.action();
+        assert_eq!(
+            action,
+            ChatClear::Timeout {
+                user: IntoCow::into_cow("ronni"),
+                user_id: Some(IntoCow::into_cow("87654321")),
+                duration: Some(Duration::from_secs(350)),
+                expires_at: None,
+            }
+        );
+        assert_eq!(action.user().map(|s| s.as_str()), Some("ronni"));
+        assert_eq!(action.user_id().map(|s| s.as_str()), Some("87654321"));
+
+        // a malformed duration is still a timeout, not a ban
+        for duration in ["", "ten", "-1"] {
+            let input = format!("@ban-duration={duration};tmi-sent-ts=1642715756806 :tmi.twitch.tv CLEARCHAT #museun :ronni\r\n");
+            let clear_chat = test_util::parse_as::<ClearChat>(&input);
+            let action = clear_chat.action();
+            assert_eq!(
+                action,
+                ChatClear::Timeout {
+                    user: IntoCow::into_cow("ronni"),
+                    user_id: None,
+                    duration: None,
+                    expires_at: None,
+                },
+                "{duration:?}"
+            );
+            assert_eq!(action.to_string(), "ronni was timed out");
+        }
+    }
 }
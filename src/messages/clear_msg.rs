@@ -1,4 +1,5 @@
-use std::borrow::Cow;
+use crate::{known_tags::KnownTag, Timestamp};
+use alloc::borrow::Cow;
 
 use super::{Message, Tags};
 
@@ -19,22 +20,41 @@ pub struct ClearMsg<'a> {
 impl<'a> ClearMsg<'a> {
     /// The name of the user who sent the message.
     pub fn login(&self) -> Option<&super::UserNameRef> {
-        self.tags.get("login").map(Into::into)
+        self.tags.get(KnownTag::Login).map(Into::into)
     }
 
     /// The ID of the channel (chat room) where the message was removed from.
     pub fn room_id(&self) -> Option<&super::UserIdRef> {
-        self.tags.get("room-id").map(Into::into)
+        self.tags.get(KnownTag::RoomId).map(Into::into)
+    }
+
+    /// Where this message came from, if it was delivered through a [shared chat](super::SharedChatOrigin) session
+    pub fn shared_chat(&self) -> Option<super::SharedChatOrigin<'_>> {
+        super::SharedChatOrigin::from_tags(&self.tags)
+    }
+
+    /// Determines whether this message was sent in the channel it was delivered to
+    ///
+    /// In a shared chat session the same message is delivered to every participating channel, this is only true for one of them.
+    pub fn is_from_this_channel(&self) -> bool {
+        super::shared_chat::is_from_this_channel(&self.tags)
     }
 
     /// A UUID that identifies the message that was removed.
     pub fn target_msg_id(&self) -> Option<&super::UserIdRef> {
-        self.tags.get("target-msg-id").map(Into::into)
+        self.tags.get(KnownTag::TargetMsgId).map(Into::into)
     }
 
     /// The UNIX timestamp.
+    ///
+    /// See [`timestamp`](Self::timestamp) for a parsed version
     pub fn tmi_sent_ts(&self) -> Option<&str> {
-        self.tags.get("tmi-sent-ts")
+        self.tags.get(KnownTag::TmiSentTs)
+    }
+
+    /// When the message was sent, from the [`tmi_sent_ts`](Self::tmi_sent_ts)
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.tags.parsed(KnownTag::TmiSentTs)?.ok()
     }
 }
 
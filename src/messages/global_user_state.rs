@@ -1,6 +1,6 @@
-use std::borrow::Cow;
+use alloc::borrow::Cow;
 
-use crate::{parse_badges, Badge, Color};
+use crate::{known_tags::KnownTag, parse_badges, Badge, Color};
 
 use super::{EmoteSetIdRef, Message, Tags, UserType};
 
@@ -18,7 +18,7 @@ impl<'a> GlobalUserState<'a> {
     /// Contains metadata related to the chat badges in the [`badges`](Self::badges) tag.
     pub fn badge_info<'t: 'a>(&'t self) -> impl Iterator<Item = Badge<'a>> + 't {
         self.tags
-            .get("badge-info")
+            .get(KnownTag::BadgeInfo)
             .into_iter()
             .flat_map(parse_badges)
     }
@@ -39,13 +39,13 @@ impl<'a> GlobalUserState<'a> {
 
     /// The user’s display name
     pub fn display_name(&self) -> Option<&super::DisplayNameRef> {
-        self.tags.get("display-name").map(Into::into)
+        self.tags.get(KnownTag::DisplayName).map(Into::into)
     }
 
     /// A comma-delimited list of IDs that identify the emote sets that the user has access to. To access the emotes in the set, use the [Get Emote Sets](https://dev.twitch.tv/docs/api/reference#get-emote-sets) API.
     pub fn emote_sets(&self) -> impl Iterator<Item = &EmoteSetIdRef> {
         self.tags
-            .get("emote-sets")
+            .get(KnownTag::EmoteSets)
             .into_iter()
             .flat_map(|s| s.split(','))
             .map(Into::into)
@@ -53,18 +53,18 @@ impl<'a> GlobalUserState<'a> {
 
     /// Indicates whether the user has site-wide commercial free mode enabled.
     pub fn is_turbo(&self) -> bool {
-        self.tags.bool("turbo")
+        self.tags.bool(KnownTag::Turbo)
     }
 
     /// The user’s ID.
     pub fn user_id(&self) -> Option<&super::UserIdRef> {
-        self.tags.get("user-id").map(Into::into)
+        self.tags.get(KnownTag::UserId).map(Into::into)
     }
 
     /// The type of user.
     pub fn user_type(&self) -> UserType {
         self.tags
-            .get("user-type")
+            .get(KnownTag::UserType)
             .map(UserType::parse)
             .unwrap_or_default()
     }
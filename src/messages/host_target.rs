@@ -1,6 +1,6 @@
 #![allow(missing_docs, deprecated)]
 
-use std::borrow::Cow;
+use alloc::borrow::Cow;
 
 use super::Message;
 
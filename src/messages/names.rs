@@ -0,0 +1,169 @@
+use alloc::borrow::Cow;
+
+use super::{IntoCow, Message, UserNameRef};
+
+/// A list of users in a channel (`353`), sent after joining a channel
+///
+/// Twitch may split a large list over several of these, followed by an [`EndOfNames`]
+///
+/// *NOTE* this requires the `twitch.tv/membership` [`capability`](crate::encode::Capability)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Names<'a> {
+    /// The name of the connected user
+    pub name: Cow<'a, UserNameRef>,
+    /// The channel these users are in
+    pub channel: Cow<'a, str>,
+    /// The space-separated user logins, see [`users`](Self::users)
+    pub names: Cow<'a, str>,
+    /// The raw underlying string
+    pub raw: Cow<'a, str>,
+}
+
+impl<'a> Names<'a> {
+    /// The logins of the users in the channel
+    ///
+    /// ```rust
+    /// # use twitch_message::messages::Names;
+    /// let input = ":museun.tmi.twitch.tv 353 museun = #museun :museun shaken_bot\r\n";
+    /// let names = twitch_message::parse_as::<Names>(input)?;
+    /// assert_eq!(names.channel, "#museun");
+    ///
+    /// let users = names.users().map(|name| name.as_str()).collect::<Vec<_>>();
+    /// assert_eq!(users, ["museun", "shaken_bot"]);
+    /// # Ok::<(),Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn users(&self) -> impl Iterator<Item = &UserNameRef> + '_ {
+        self.names.split_ascii_whitespace().map(Into::into)
+    }
+
+    fn validate(value: &Message<'_>) -> bool {
+        value.args.len() >= 3 && value.data.is_some()
+    }
+}
+
+impl<'a> TryFrom<Message<'a>> for Names<'a> {
+    type Error = Message<'a>;
+
+    fn try_from(mut value: Message<'a>) -> Result<Self, Self::Error> {
+        if !Self::validate(&value) {
+            return Err(value);
+        }
+
+        Ok(Self {
+            channel: value.args.remove(2),
+            name: IntoCow::into_cow(value.args.remove(0)),
+            names: value.data.unwrap(),
+            raw: value.raw,
+        })
+    }
+}
+
+impl<'a, 'b> TryFrom<&'b Message<'a>> for Names<'a> {
+    type Error = &'b Message<'a>;
+
+    fn try_from(value: &'b Message<'a>) -> Result<Self, Self::Error> {
+        if !Self::validate(value) {
+            return Err(value);
+        }
+
+        Ok(Self {
+            name: IntoCow::into_cow(value.args[0].clone()),
+            channel: value.args[2].clone(),
+            names: value.data.clone().unwrap(),
+            raw: value.raw.clone(),
+        })
+    }
+}
+
+/// The end of the [`Names`] list (`366`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct EndOfNames<'a> {
+    /// The name of the connected user
+    pub name: Cow<'a, UserNameRef>,
+    /// The channel the list was for
+    pub channel: Cow<'a, str>,
+    /// The raw underlying string
+    pub raw: Cow<'a, str>,
+}
+
+impl EndOfNames<'_> {
+    fn validate(value: &Message<'_>) -> bool {
+        value.args.len() >= 2
+    }
+}
+
+impl<'a> TryFrom<Message<'a>> for EndOfNames<'a> {
+    type Error = Message<'a>;
+
+    fn try_from(mut value: Message<'a>) -> Result<Self, Self::Error> {
+        if !Self::validate(&value) {
+            return Err(value);
+        }
+
+        Ok(Self {
+            channel: value.args.remove(1),
+            name: IntoCow::into_cow(value.args.remove(0)),
+            raw: value.raw,
+        })
+    }
+}
+
+impl<'a, 'b> TryFrom<&'b Message<'a>> for EndOfNames<'a> {
+    type Error = &'b Message<'a>;
+
+    fn try_from(value: &'b Message<'a>) -> Result<Self, Self::Error> {
+        if !Self::validate(value) {
+            return Err(value);
+        }
+
+        Ok(Self {
+            name: IntoCow::into_cow(value.args[0].clone()),
+            channel: value.args[1].clone(),
+            raw: value.raw.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+
+    #[test]
+    fn names() {
+        let input = ":museun.tmi.twitch.tv 353 museun = #museun :museun shaken_bot\r\n";
+
+        let raw = test_util::raw(input);
+        let names = test_util::parse_as::<Names>(input);
+        assert_eq!(
+            names,
+            Names {
+                name: IntoCow::into_cow("museun"),
+                channel: Cow::from("#museun"),
+                names: Cow::from("museun shaken_bot"),
+                raw,
+            }
+        );
+        assert_eq!(names.users().count(), 2);
+
+        let input = ":museun.tmi.twitch.tv 353 museun #museun\r\n";
+        assert!(crate::parse_as::<Names>(input).is_err());
+    }
+
+    #[test]
+    fn end_of_names() {
+        let input = ":museun.tmi.twitch.tv 366 museun #museun :End of /NAMES list\r\n";
+
+        let raw = test_util::raw(input);
+        assert_eq!(
+            test_util::parse_as::<EndOfNames>(input),
+            EndOfNames {
+                name: IntoCow::into_cow("museun"),
+                channel: Cow::from("#museun"),
+                raw,
+            }
+        );
+    }
+}
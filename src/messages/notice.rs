@@ -1,5 +1,6 @@
 #![allow(missing_docs, deprecated)]
-use std::borrow::Cow;
+use crate::{known_tags::KnownTag, IntoStatic};
+use alloc::borrow::Cow;
 
 use super::{Message, Tags};
 
@@ -19,15 +20,28 @@ pub struct Notice<'a> {
 }
 
 impl<'a> Notice<'a> {
-    pub fn notice_kind(&self) -> NoticeKind {
-        self.tags
-            .get("msg-id")
-            .map(NoticeKind::parse)
-            .unwrap_or_default()
+    /// The kind of notice
+    ///
+    /// Server-level notices, such as a failed login, don't have a `msg-id` so they are classified by their message
+    ///
+    /// ```rust
+    /// # #![allow(deprecated)]
+    /// # use twitch_message::messages::{Notice, NoticeKind};
+    /// let input = ":tmi.twitch.tv NOTICE * :Login authentication failed\r\n";
+    /// let notice = twitch_message::parse_as::<Notice>(input)?;
+    /// assert_eq!(notice.notice_kind(), NoticeKind::LoginAuthenticationFailed);
+    /// assert!(notice.notice_kind().is_auth_failure());
+    /// # Ok::<(),Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn notice_kind(&self) -> NoticeKind<'_> {
+        match self.tags.get(KnownTag::MsgId) {
+            Some(msg_id) => NoticeKind::from_msg_id(msg_id),
+            None => NoticeKind::parse_server_message(&self.message),
+        }
     }
 
     pub fn target_user_id(&self) -> Option<&super::UserIdRef> {
-        self.tags.get("target-user-id").map(Into::into)
+        self.tags.get(KnownTag::TargetUserId).map(Into::into)
     }
 }
 
@@ -37,339 +51,377 @@ impl Notice<'_> {
     }
 }
 
-#[derive(Copy, Clone, Default, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
-#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
-pub enum NoticeKind {
-    AlreadyBanned,
-    AlreadyEmoteOnlyOff,
-    AlreadyEmoteOnlyOn,
-    AlreadyFollowersOff,
-    AlreadyFollowersOn,
-    AlreadyR9kOff,
-    AlreadyR9kOn,
-    AlreadySlowOff,
-    AlreadySlowOn,
-    AlreadySubsOff,
-    AlreadySubsOn,
-    AutohostReceive,
-    BadBanAdmin,
-    BadBanAnon,
-    BadBanBroadcaster,
-    BadBanMod,
-    BadBanSelf,
-    BadBanStaff,
-    BadCommercialError,
-    BadDeleteMessageBroadcaster,
-    BadDeleteMessageMod,
-    BadHostError,
-    BadHostHosting,
-    BadHostRateExceeded,
-    BadHostRejected,
-    BadHostSelf,
-    BadModBanned,
-    BadModMod,
-    BadSlowDuration,
-    BadTimeoutAdmin,
-    BadTimeoutAnon,
-    BadTimeoutBroadcaster,
-    BadTimeoutDuration,
-    BadTimeoutMod,
-    BadTimeoutSelf,
-    BadTimeoutStaff,
-    BadUnbanNoBan,
-    BadUnhostError,
-    BadUnmodMod,
-    BadVipGranteeBanned,
-    BadVipGranteeAlreadyVip,
-    BadVipMaxVipsReached,
-    BadVipAchievementIncomplete,
-    BadUnvipGranteeNotVip,
-    BanSuccess,
-    CmdsAvailable,
-    ColorChanged,
-    CommercialSuccess,
-    DeleteMessageSuccess,
-    DeleteStaffMessageSuccess,
-    EmoteOnlyOff,
-    EmoteOnlyOn,
-    FollowersOff,
-    FollowersOn,
-    FollowersOnZero,
-    HostOff,
-    HostOn,
-    HostReceive,
-    HostReceiveNoCount,
-    HostTargetWentOffline,
-    HostsRemaining,
-    InvalidUser,
-    ModSuccess,
-    MsgBanned,
-    MsgBadCharacters,
-    MsgChannelBlocked,
-    MsgChannelSuspended,
-    MsgDuplicate,
-    MsgEmoteonly,
-    MsgFollowersonly,
-    MsgFollowersonlyFollowed,
-    MsgFollowersonlyZero,
-    MsgR9k,
-    MsgRatelimit,
-    MsgRejected,
-    MsgRejectedMandatory,
-    MsgRequiresVerifiedPhoneNumber,
-    MsgSlowmode,
-    MsgSubsonly,
-    MsgSuspended,
-    MsgTimedout,
-    MsgVerifiedEmail,
-    NoHelp,
-    NoMods,
-    NoVips,
-    NotHosting,
-    NoPermission,
-    R9kOff,
-    R9kOn,
-    RaidErrorAlreadyRaiding,
-    RaidErrorForbidden,
-    RaidErrorSelf,
-    RaidErrorTooManyViewers,
-    RaidErrorUnexpected,
-    RaidNoticeMature,
-    RaidNoticeRestrictedChat,
-    RoomMods,
-    SlowOff,
-    SlowOn,
-    SubsOff,
-    SubsOn,
-    TimeoutNoTimeout,
-    TimeoutSuccess,
-    TosBan,
-    TurboOnlyColor,
-    UnavailableCommand,
-    UnbanSuccess,
-    UnmodSuccess,
-    UnraidErrorNoActiveRaid,
-    UnraidErrorUnexpected,
-    UnraidSuccess,
-    UnrecognizedCmd,
-    UntimeoutBanned,
-    UntimeoutSuccess,
-    UnvipSuccess,
-    UsageBan,
-    UsageClear,
-    UsageColor,
-    UsageCommercial,
-    UsageDisconnect,
-    UsageDelete,
-    UsageEmoteOnlyOff,
-    UsageEmoteOnlyOn,
-    UsageFollowersOff,
-    UsageFollowersOn,
-    UsageHelp,
-    UsageHost,
-    UsageMarker,
-    UsageMe,
-    UsageMod,
-    UsageMods,
-    UsageR9kOff,
-    UsageR9kOn,
-    UsageRaid,
-    UsageSlowOff,
-    UsageSlowOn,
-    UsageSubsOff,
-    UsageSubsOn,
-    UsageTimeout,
-    UsageUnban,
-    UsageUnhost,
-    UsageUnmod,
-    UsageUnraid,
-    UsageUntimeout,
-    UsageUnvip,
-    UsageUser,
-    UsageVip,
-    UsageVips,
-    UsageWhisper,
-    VipSuccess,
-    VipsSuccess,
-    WhisperBanned,
-    WhisperBannedRecipient,
-    WhisperInvalidLogin,
-    WhisperInvalidSelf,
-    WhisperLimitPerMin,
-    WhisperLimitPerSec,
-    WhisperRestricted,
-    WhisperRestrictedRecipient,
-    #[default]
-    Unknown,
+macro_rules! notice_kinds {
+    ($($ident:ident => $id:literal [$category:ident, $outcome:ident])*) => {
+        /// The kind of [`Notice`], from its `msg-id`
+        ///
+        /// See [`NoticeKind::category`] and [`NoticeKind::outcome`] for classifying these.
+        #[derive(Clone, Default, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[non_exhaustive]
+        pub enum NoticeKind<'a> {
+            $(
+                #[doc = concat!("`", $id, "`")]
+                $ident,
+            )*
+            /// `Login authentication failed`, the OAuth token was invalid or expired
+            ///
+            /// This is a server-level notice, it has no `msg-id`
+            LoginAuthenticationFailed,
+            /// `Improperly formatted auth`, the `PASS` was not an `oauth:` token
+            ///
+            /// This is a server-level notice, it has no `msg-id`
+            ImproperlyFormattedAuth,
+            /// `Login unsuccessful`
+            ///
+            /// This is a server-level notice, it has no `msg-id`
+            LoginUnsuccessful,
+            /// A `msg-id` this crate doesn't know about, with its original string
+            Other(Cow<'a, str>),
+            /// A notice without a `msg-id` that isn't a known server-level notice
+            #[default]
+            Unknown,
+        }
+
+        impl<'a> NoticeKind<'a> {
+            /// Get the kind for this `msg-id`
+            ///
+            /// An unknown `msg-id` is kept as [`NoticeKind::Other`]
+            pub fn from_msg_id(input: &'a str) -> Self {
+                match input {
+                    $($id => Self::$ident,)*
+                    other => Self::Other(Cow::from(other)),
+                }
+            }
+
+            /// The `msg-id` of this kind
+            ///
+            /// Server-level notices and [`NoticeKind::Unknown`] don't have one
+            pub fn msg_id(&self) -> Option<&str> {
+                Some(match self {
+                    $(Self::$ident => $id,)*
+                    Self::Other(id) => id,
+                    Self::LoginAuthenticationFailed
+                    | Self::ImproperlyFormattedAuth
+                    | Self::LoginUnsuccessful
+                    | Self::Unknown => return None,
+                })
+            }
+
+            /// What this notice is about
+            pub const fn category(&self) -> NoticeCategory {
+                match self {
+                    $(Self::$ident => NoticeCategory::$category,)*
+                    Self::LoginAuthenticationFailed
+                    | Self::ImproperlyFormattedAuth
+                    | Self::LoginUnsuccessful => NoticeCategory::Auth,
+                    Self::Other(..) | Self::Unknown => NoticeCategory::Unknown,
+                }
+            }
+
+            /// Whether this notice reports a success, an error, usage help or information
+            pub const fn outcome(&self) -> NoticeOutcome {
+                match self {
+                    $(Self::$ident => NoticeOutcome::$outcome,)*
+                    Self::LoginAuthenticationFailed
+                    | Self::ImproperlyFormattedAuth
+                    | Self::LoginUnsuccessful => NoticeOutcome::Error,
+                    Self::Other(..) | Self::Unknown => NoticeOutcome::Unknown,
+                }
+            }
+        }
+
+        impl<'a> IntoStatic for NoticeKind<'a>
+        where
+            'static: 'a,
+        {
+            type Output = NoticeKind<'static>;
+            fn into_static(self) -> Self::Output {
+                match self {
+                    $(Self::$ident => NoticeKind::$ident,)*
+                    Self::LoginAuthenticationFailed => NoticeKind::LoginAuthenticationFailed,
+                    Self::ImproperlyFormattedAuth => NoticeKind::ImproperlyFormattedAuth,
+                    Self::LoginUnsuccessful => NoticeKind::LoginUnsuccessful,
+                    Self::Other(id) => NoticeKind::Other(id.into_static()),
+                    Self::Unknown => NoticeKind::Unknown,
+                }
+            }
+        }
+    };
+}
+
+notice_kinds! {
+    AlreadyBanned => "already_banned" [BanTimeout, Error]
+    AlreadyEmoteOnlyOff => "already_emote_only_off" [RoomMode, Error]
+    AlreadyEmoteOnlyOn => "already_emote_only_on" [RoomMode, Error]
+    AlreadyFollowersOff => "already_followers_off" [RoomMode, Error]
+    AlreadyFollowersOn => "already_followers_on" [RoomMode, Error]
+    AlreadyR9kOff => "already_r9k_off" [RoomMode, Error]
+    AlreadyR9kOn => "already_r9k_on" [RoomMode, Error]
+    AlreadySlowOff => "already_slow_off" [RoomMode, Error]
+    AlreadySlowOn => "already_slow_on" [RoomMode, Error]
+    AlreadySubsOff => "already_subs_off" [RoomMode, Error]
+    AlreadySubsOn => "already_subs_on" [RoomMode, Error]
+    AutohostReceive => "autohost_receive" [Host, Info]
+    BadBanAdmin => "bad_ban_admin" [BanTimeout, Error]
+    BadBanAnon => "bad_ban_anon" [BanTimeout, Error]
+    BadBanBroadcaster => "bad_ban_broadcaster" [BanTimeout, Error]
+    BadBanMod => "bad_ban_mod" [BanTimeout, Error]
+    BadBanSelf => "bad_ban_self" [BanTimeout, Error]
+    BadBanStaff => "bad_ban_staff" [BanTimeout, Error]
+    BadCommercialError => "bad_commercial_error" [Command, Error]
+    BadDeleteMessageBroadcaster => "bad_delete_message_broadcaster" [Command, Error]
+    BadDeleteMessageMod => "bad_delete_message_mod" [Command, Error]
+    BadHostError => "bad_host_error" [Host, Error]
+    BadHostHosting => "bad_host_hosting" [Host, Error]
+    BadHostRateExceeded => "bad_host_rate_exceeded" [RateLimit, Error]
+    BadHostRejected => "bad_host_rejected" [Host, Error]
+    BadHostSelf => "bad_host_self" [Host, Error]
+    BadModBanned => "bad_mod_banned" [Roles, Error]
+    BadModMod => "bad_mod_mod" [Roles, Error]
+    BadSlowDuration => "bad_slow_duration" [RoomMode, Error]
+    BadTimeoutAdmin => "bad_timeout_admin" [BanTimeout, Error]
+    BadTimeoutAnon => "bad_timeout_anon" [BanTimeout, Error]
+    BadTimeoutBroadcaster => "bad_timeout_broadcaster" [BanTimeout, Error]
+    BadTimeoutDuration => "bad_timeout_duration" [BanTimeout, Error]
+    BadTimeoutMod => "bad_timeout_mod" [BanTimeout, Error]
+    BadTimeoutSelf => "bad_timeout_self" [BanTimeout, Error]
+    BadTimeoutStaff => "bad_timeout_staff" [BanTimeout, Error]
+    BadUnbanNoBan => "bad_unban_no_ban" [BanTimeout, Error]
+    BadUnhostError => "bad_unhost_error" [Host, Error]
+    BadUnmodMod => "bad_unmod_mod" [Roles, Error]
+    BadVipGranteeBanned => "bad_vip_grantee_banned" [Roles, Error]
+    BadVipGranteeAlreadyVip => "bad_vip_grantee_already_vip" [Roles, Error]
+    BadVipMaxVipsReached => "bad_vip_max_vips_reached" [Roles, Error]
+    BadVipAchievementIncomplete => "bad_vip_achievement_incomplete" [Roles, Error]
+    BadUnvipGranteeNotVip => "bad_unvip_grantee_not_vip" [Roles, Error]
+    BanSuccess => "ban_success" [BanTimeout, Success]
+    CmdsAvailable => "cmds_available" [Command, Info]
+    ColorChanged => "color_changed" [Command, Success]
+    CommercialSuccess => "commercial_success" [Command, Success]
+    DeleteMessageSuccess => "delete_message_success" [Command, Success]
+    DeleteStaffMessageSuccess => "delete_staff_message_success" [Command, Success]
+    EmoteOnlyOff => "emote_only_off" [RoomMode, Success]
+    EmoteOnlyOn => "emote_only_on" [RoomMode, Success]
+    FollowersOff => "followers_off" [RoomMode, Success]
+    FollowersOn => "followers_on" [RoomMode, Success]
+    FollowersOnZero => "followers_on_zero" [RoomMode, Success]
+    HostOff => "host_off" [Host, Success]
+    HostOn => "host_on" [Host, Success]
+    HostReceive => "host_receive" [Host, Info]
+    HostReceiveNoCount => "host_receive_no_count" [Host, Info]
+    HostTargetWentOffline => "host_target_went_offline" [Host, Info]
+    HostsRemaining => "hosts_remaining" [Host, Info]
+    InvalidUser => "invalid_user" [Command, Error]
+    ModSuccess => "mod_success" [Roles, Success]
+    MsgBanned => "msg_banned" [SendRejected, Error]
+    MsgBadCharacters => "msg_bad_characters" [SendRejected, Error]
+    MsgChannelBlocked => "msg_channel_blocked" [SendRejected, Error]
+    MsgChannelSuspended => "msg_channel_suspended" [SendRejected, Error]
+    MsgDuplicate => "msg_duplicate" [RateLimit, Error]
+    MsgEmoteonly => "msg_emoteonly" [SendRejected, Error]
+    MsgFollowersonly => "msg_followersonly" [SendRejected, Error]
+    MsgFollowersonlyFollowed => "msg_followersonly_followed" [TemporarilyRejected, Error]
+    MsgFollowersonlyZero => "msg_followersonly_zero" [SendRejected, Error]
+    MsgR9k => "msg_r9k" [SendRejected, Error]
+    MsgRatelimit => "msg_ratelimit" [RateLimit, Error]
+    MsgRejected => "msg_rejected" [SendRejected, Error]
+    MsgRejectedMandatory => "msg_rejected_mandatory" [SendRejected, Error]
+    MsgRequiresVerifiedPhoneNumber => "msg_requires_verified_phone_number" [SendRejected, Error]
+    MsgSlowmode => "msg_slowmode" [RateLimit, Error]
+    MsgSubsonly => "msg_subsonly" [SendRejected, Error]
+    MsgSuspended => "msg_suspended" [SendRejected, Error]
+    MsgTimedout => "msg_timedout" [TemporarilyRejected, Error]
+    MsgVerifiedEmail => "msg_verified_email" [SendRejected, Error]
+    NoHelp => "no_help" [Command, Info]
+    NoMods => "no_mods" [Roles, Info]
+    NoVips => "no_vips" [Roles, Info]
+    NotHosting => "not_hosting" [Host, Error]
+    NoPermission => "no_permission" [Command, Error]
+    R9kOff => "r9k_off" [RoomMode, Success]
+    R9kOn => "r9k_on" [RoomMode, Success]
+    RaidErrorAlreadyRaiding => "raid_error_already_raiding" [Raid, Error]
+    RaidErrorForbidden => "raid_error_forbidden" [Raid, Error]
+    RaidErrorSelf => "raid_error_self" [Raid, Error]
+    RaidErrorTooManyViewers => "raid_error_too_many_viewers" [Raid, Error]
+    RaidErrorUnexpected => "raid_error_unexpected" [Raid, Error]
+    RaidNoticeMature => "raid_notice_mature" [Raid, Info]
+    RaidNoticeRestrictedChat => "raid_notice_restricted_chat" [Raid, Info]
+    RoomMods => "room_mods" [Roles, Info]
+    SlowOff => "slow_off" [RoomMode, Success]
+    SlowOn => "slow_on" [RoomMode, Success]
+    SubsOff => "subs_off" [RoomMode, Success]
+    SubsOn => "subs_on" [RoomMode, Success]
+    TimeoutNoTimeout => "timeout_no_timeout" [BanTimeout, Error]
+    TimeoutSuccess => "timeout_success" [BanTimeout, Success]
+    TosBan => "tos_ban" [BanTimeout, Error]
+    TurboOnlyColor => "turbo_only_color" [Command, Error]
+    UnavailableCommand => "unavailable_command" [Command, Error]
+    UnbanSuccess => "unban_success" [BanTimeout, Success]
+    UnmodSuccess => "unmod_success" [Roles, Success]
+    UnraidErrorNoActiveRaid => "unraid_error_no_active_raid" [Raid, Error]
+    UnraidErrorUnexpected => "unraid_error_unexpected" [Raid, Error]
+    UnraidSuccess => "unraid_success" [Raid, Success]
+    UnrecognizedCmd => "unrecognized_cmd" [Command, Error]
+    UntimeoutBanned => "untimeout_banned" [BanTimeout, Error]
+    UntimeoutSuccess => "untimeout_success" [BanTimeout, Success]
+    UnvipSuccess => "unvip_success" [Roles, Success]
+    UsageBan => "usage_ban" [BanTimeout, Usage]
+    UsageClear => "usage_clear" [Command, Usage]
+    UsageColor => "usage_color" [Command, Usage]
+    UsageCommercial => "usage_commercial" [Command, Usage]
+    UsageDisconnect => "usage_disconnect" [Command, Usage]
+    UsageDelete => "usage_delete" [Command, Usage]
+    UsageEmoteOnlyOff => "usage_emote_only_off" [RoomMode, Usage]
+    UsageEmoteOnlyOn => "usage_emote_only_on" [RoomMode, Usage]
+    UsageFollowersOff => "usage_followers_off" [RoomMode, Usage]
+    UsageFollowersOn => "usage_followers_on" [RoomMode, Usage]
+    UsageHelp => "usage_help" [Command, Usage]
+    UsageHost => "usage_host" [Host, Usage]
+    UsageMarker => "usage_marker" [Command, Usage]
+    UsageMe => "usage_me" [Command, Usage]
+    UsageMod => "usage_mod" [Roles, Usage]
+    UsageMods => "usage_mods" [Roles, Usage]
+    UsageR9kOff => "usage_r9k_off" [RoomMode, Usage]
+    UsageR9kOn => "usage_r9k_on" [RoomMode, Usage]
+    UsageRaid => "usage_raid" [Raid, Usage]
+    UsageSlowOff => "usage_slow_off" [RoomMode, Usage]
+    UsageSlowOn => "usage_slow_on" [RoomMode, Usage]
+    UsageSubsOff => "usage_subs_off" [RoomMode, Usage]
+    UsageSubsOn => "usage_subs_on" [RoomMode, Usage]
+    UsageTimeout => "usage_timeout" [BanTimeout, Usage]
+    UsageUnban => "usage_unban" [BanTimeout, Usage]
+    UsageUnhost => "usage_unhost" [Host, Usage]
+    UsageUnmod => "usage_unmod" [Roles, Usage]
+    UsageUnraid => "usage_unraid" [Raid, Usage]
+    UsageUntimeout => "usage_untimeout" [BanTimeout, Usage]
+    UsageUnvip => "usage_unvip" [Roles, Usage]
+    UsageUser => "usage_user" [Command, Usage]
+    UsageVip => "usage_vip" [Roles, Usage]
+    UsageVips => "usage_vips" [Roles, Usage]
+    UsageWhisper => "usage_whisper" [Command, Usage]
+    VipSuccess => "vip_success" [Roles, Success]
+    VipsSuccess => "vips_success" [Roles, Success]
+    WhisperBanned => "whisper_banned" [SendRejected, Error]
+    WhisperBannedRecipient => "whisper_banned_recipient" [SendRejected, Error]
+    WhisperInvalidLogin => "whisper_invalid_login" [SendRejected, Error]
+    WhisperInvalidSelf => "whisper_invalid_self" [SendRejected, Error]
+    WhisperLimitPerMin => "whisper_limit_per_min" [RateLimit, Error]
+    WhisperLimitPerSec => "whisper_limit_per_sec" [RateLimit, Error]
+    WhisperRestricted => "whisper_restricted" [SendRejected, Error]
+    WhisperRestrictedRecipient => "whisper_restricted_recipient" [SendRejected, Error]
 }
 
-impl NoticeKind {
-    fn parse(input: &str) -> Self {
-        match input {
-            "already_banned" => Self::AlreadyBanned,
-            "already_emote_only_off" => Self::AlreadyEmoteOnlyOff,
-            "already_emote_only_on" => Self::AlreadyEmoteOnlyOn,
-            "already_followers_off" => Self::AlreadyFollowersOff,
-            "already_followers_on" => Self::AlreadyFollowersOn,
-            "already_r9k_off" => Self::AlreadyR9kOff,
-            "already_r9k_on" => Self::AlreadyR9kOn,
-            "already_slow_off" => Self::AlreadySlowOff,
-            "already_slow_on" => Self::AlreadySlowOn,
-            "already_subs_off" => Self::AlreadySubsOff,
-            "already_subs_on" => Self::AlreadySubsOn,
-            "autohost_receive" => Self::AutohostReceive,
-            "bad_ban_admin" => Self::BadBanAdmin,
-            "bad_ban_anon" => Self::BadBanAnon,
-            "bad_ban_broadcaster" => Self::BadBanBroadcaster,
-            "bad_ban_mod" => Self::BadBanMod,
-            "bad_ban_self" => Self::BadBanSelf,
-            "bad_ban_staff" => Self::BadBanStaff,
-            "bad_commercial_error" => Self::BadCommercialError,
-            "bad_delete_message_broadcaster" => Self::BadDeleteMessageBroadcaster,
-            "bad_delete_message_mod" => Self::BadDeleteMessageMod,
-            "bad_host_error" => Self::BadHostError,
-            "bad_host_hosting" => Self::BadHostHosting,
-            "bad_host_rate_exceeded" => Self::BadHostRateExceeded,
-            "bad_host_rejected" => Self::BadHostRejected,
-            "bad_host_self" => Self::BadHostSelf,
-            "bad_mod_banned" => Self::BadModBanned,
-            "bad_mod_mod" => Self::BadModMod,
-            "bad_slow_duration" => Self::BadSlowDuration,
-            "bad_timeout_admin" => Self::BadTimeoutAdmin,
-            "bad_timeout_anon" => Self::BadTimeoutAnon,
-            "bad_timeout_broadcaster" => Self::BadTimeoutBroadcaster,
-            "bad_timeout_duration" => Self::BadTimeoutDuration,
-            "bad_timeout_mod" => Self::BadTimeoutMod,
-            "bad_timeout_self" => Self::BadTimeoutSelf,
-            "bad_timeout_staff" => Self::BadTimeoutStaff,
-            "bad_unban_no_ban" => Self::BadUnbanNoBan,
-            "bad_unhost_error" => Self::BadUnhostError,
-            "bad_unmod_mod" => Self::BadUnmodMod,
-            "bad_vip_grantee_banned" => Self::BadVipGranteeBanned,
-            "bad_vip_grantee_already_vip" => Self::BadVipGranteeAlreadyVip,
-            "bad_vip_max_vips_reached" => Self::BadVipMaxVipsReached,
-            "bad_vip_achievement_incomplete" => Self::BadVipAchievementIncomplete,
-            "bad_unvip_grantee_not_vip" => Self::BadUnvipGranteeNotVip,
-            "ban_success" => Self::BanSuccess,
-            "cmds_available" => Self::CmdsAvailable,
-            "color_changed" => Self::ColorChanged,
-            "commercial_success" => Self::CommercialSuccess,
-            "delete_message_success" => Self::DeleteMessageSuccess,
-            "delete_staff_message_success" => Self::DeleteStaffMessageSuccess,
-            "emote_only_off" => Self::EmoteOnlyOff,
-            "emote_only_on" => Self::EmoteOnlyOn,
-            "followers_off" => Self::FollowersOff,
-            "followers_on" => Self::FollowersOn,
-            "followers_on_zero" => Self::FollowersOnZero,
-            "host_off" => Self::HostOff,
-            "host_on" => Self::HostOn,
-            "host_receive" => Self::HostReceive,
-            "host_receive_no_count" => Self::HostReceiveNoCount,
-            "host_target_went_offline" => Self::HostTargetWentOffline,
-            "hosts_remaining" => Self::HostsRemaining,
-            "invalid_user" => Self::InvalidUser,
-            "mod_success" => Self::ModSuccess,
-            "msg_banned" => Self::MsgBanned,
-            "msg_bad_characters" => Self::MsgBadCharacters,
-            "msg_channel_blocked" => Self::MsgChannelBlocked,
-            "msg_channel_suspended" => Self::MsgChannelSuspended,
-            "msg_duplicate" => Self::MsgDuplicate,
-            "msg_emoteonly" => Self::MsgEmoteonly,
-            "msg_followersonly" => Self::MsgFollowersonly,
-            "msg_followersonly_followed" => Self::MsgFollowersonlyFollowed,
-            "msg_followersonly_zero" => Self::MsgFollowersonlyZero,
-            "msg_r9k" => Self::MsgR9k,
-            "msg_ratelimit" => Self::MsgRatelimit,
-            "msg_rejected" => Self::MsgRejected,
-            "msg_rejected_mandatory" => Self::MsgRejectedMandatory,
-            "msg_requires_verified_phone_number" => Self::MsgRequiresVerifiedPhoneNumber,
-            "msg_slowmode" => Self::MsgSlowmode,
-            "msg_subsonly" => Self::MsgSubsonly,
-            "msg_suspended" => Self::MsgSuspended,
-            "msg_timedout" => Self::MsgTimedout,
-            "msg_verified_email" => Self::MsgVerifiedEmail,
-            "no_help" => Self::NoHelp,
-            "no_mods" => Self::NoMods,
-            "no_vips" => Self::NoVips,
-            "not_hosting" => Self::NotHosting,
-            "no_permission" => Self::NoPermission,
-            "r9k_off" => Self::R9kOff,
-            "r9k_on" => Self::R9kOn,
-            "raid_error_already_raiding" => Self::RaidErrorAlreadyRaiding,
-            "raid_error_forbidden" => Self::RaidErrorForbidden,
-            "raid_error_self" => Self::RaidErrorSelf,
-            "raid_error_too_many_viewers" => Self::RaidErrorTooManyViewers,
-            "raid_error_unexpected" => Self::RaidErrorUnexpected,
-            "raid_notice_mature" => Self::RaidNoticeMature,
-            "raid_notice_restricted_chat" => Self::RaidNoticeRestrictedChat,
-            "room_mods" => Self::RoomMods,
-            "slow_off" => Self::SlowOff,
-            "slow_on" => Self::SlowOn,
-            "subs_off" => Self::SubsOff,
-            "subs_on" => Self::SubsOn,
-            "timeout_no_timeout" => Self::TimeoutNoTimeout,
-            "timeout_success" => Self::TimeoutSuccess,
-            "tos_ban" => Self::TosBan,
-            "turbo_only_color" => Self::TurboOnlyColor,
-            "unavailable_command" => Self::UnavailableCommand,
-            "unban_success" => Self::UnbanSuccess,
-            "unmod_success" => Self::UnmodSuccess,
-            "unraid_error_no_active_raid" => Self::UnraidErrorNoActiveRaid,
-            "unraid_error_unexpected" => Self::UnraidErrorUnexpected,
-            "unraid_success" => Self::UnraidSuccess,
-            "unrecognized_cmd" => Self::UnrecognizedCmd,
-            "untimeout_banned" => Self::UntimeoutBanned,
-            "untimeout_success" => Self::UntimeoutSuccess,
-            "unvip_success" => Self::UnvipSuccess,
-            "usage_ban" => Self::UsageBan,
-            "usage_clear" => Self::UsageClear,
-            "usage_color" => Self::UsageColor,
-            "usage_commercial" => Self::UsageCommercial,
-            "usage_disconnect" => Self::UsageDisconnect,
-            "usage_delete" => Self::UsageDelete,
-            "usage_emote_only_off" => Self::UsageEmoteOnlyOff,
-            "usage_emote_only_on" => Self::UsageEmoteOnlyOn,
-            "usage_followers_off" => Self::UsageFollowersOff,
-            "usage_followers_on" => Self::UsageFollowersOn,
-            "usage_help" => Self::UsageHelp,
-            "usage_host" => Self::UsageHost,
-            "usage_marker" => Self::UsageMarker,
-            "usage_me" => Self::UsageMe,
-            "usage_mod" => Self::UsageMod,
-            "usage_mods" => Self::UsageMods,
-            "usage_r9k_off" => Self::UsageR9kOff,
-            "usage_r9k_on" => Self::UsageR9kOn,
-            "usage_raid" => Self::UsageRaid,
-            "usage_slow_off" => Self::UsageSlowOff,
-            "usage_slow_on" => Self::UsageSlowOn,
-            "usage_subs_off" => Self::UsageSubsOff,
-            "usage_subs_on" => Self::UsageSubsOn,
-            "usage_timeout" => Self::UsageTimeout,
-            "usage_unban" => Self::UsageUnban,
-            "usage_unhost" => Self::UsageUnhost,
-            "usage_unmod" => Self::UsageUnmod,
-            "usage_unraid" => Self::UsageUnraid,
-            "usage_untimeout" => Self::UsageUntimeout,
-            "usage_unvip" => Self::UsageUnvip,
-            "usage_user" => Self::UsageUser,
-            "usage_vip" => Self::UsageVip,
-            "usage_vips" => Self::UsageVips,
-            "usage_whisper" => Self::UsageWhisper,
-            "vip_success" => Self::VipSuccess,
-            "vips_success" => Self::VipsSuccess,
-            "whisper_banned" => Self::WhisperBanned,
-            "whisper_banned_recipient" => Self::WhisperBannedRecipient,
-            "whisper_invalid_login" => Self::WhisperInvalidLogin,
-            "whisper_invalid_self" => Self::WhisperInvalidSelf,
-            "whisper_limit_per_min" => Self::WhisperLimitPerMin,
-            "whisper_limit_per_sec" => Self::WhisperLimitPerSec,
-            "whisper_restricted" => Self::WhisperRestricted,
-            "whisper_restricted_recipient" => Self::WhisperRestrictedRecipient,
+impl<'a> NoticeKind<'a> {
+    /// Determines whether this notice means the credentials used to register were rejected
+    ///
+    /// Reconnecting with the same credentials won't succeed
+    pub const fn is_auth_failure(&self) -> bool {
+        matches!(self.category(), NoticeCategory::Auth)
+    }
+
+    /// Determines whether this notice reports an error
+    pub const fn is_error(&self) -> bool {
+        matches!(self.outcome(), NoticeOutcome::Error)
+    }
+
+    /// Determines whether this notice reports a success
+    pub const fn is_success(&self) -> bool {
+        matches!(self.outcome(), NoticeOutcome::Success)
+    }
+
+    /// Determines whether this notice is the usage help for a command
+    pub const fn is_usage(&self) -> bool {
+        matches!(self.outcome(), NoticeOutcome::Usage)
+    }
+
+    /// Determines whether a message rejected with this notice can be sent again later
+    ///
+    /// This is true for [rate limits](NoticeCategory::RateLimit) (e.g. slow mode or a duplicate message),
+    /// and for [temporary rejections](NoticeCategory::TemporarilyRejected) (e.g. a timeout).
+    /// Other [rejections](NoticeCategory::SendRejected) (e.g. being banned, or subscribers-only mode) won't succeed by waiting.
+    ///
+    /// ```rust
+    /// # use twitch_message::messages::NoticeKind;
+    /// assert!(NoticeKind::from_msg_id("msg_ratelimit").is_retryable());
+    /// assert!(NoticeKind::from_msg_id("msg_timedout").is_retryable());
+    /// assert!(!NoticeKind::from_msg_id("msg_banned").is_retryable());
+    /// ```
+    pub const fn is_retryable(&self) -> bool {
+        matches!(
+            self.category(),
+            NoticeCategory::RateLimit | NoticeCategory::TemporarilyRejected
+        )
+    }
+
+    fn parse_server_message(input: &str) -> Self {
+        match input.trim() {
+            "Login authentication failed" => Self::LoginAuthenticationFailed,
+            "Improperly formatted auth" => Self::ImproperlyFormattedAuth,
+            "Login unsuccessful" => Self::LoginUnsuccessful,
             _ => Self::Unknown,
         }
     }
 }
 
+impl core::str::FromStr for NoticeKind<'static> {
+    type Err = core::convert::Infallible;
+    /// Parses a `msg-id`, an unknown `msg-id` is kept as [`NoticeKind::Other`]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(NoticeKind::from_msg_id(input).into_static())
+    }
+}
+
+/// What a [`NoticeKind`] is about
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[non_exhaustive]
+pub enum NoticeCategory {
+    /// A message was rejected because it was sent too quickly (e.g. rate limits, slow mode or a duplicate message)
+    ///
+    /// Sending it again later can succeed
+    RateLimit,
+    /// A message was rejected until a restriction on the user ends (e.g. they are timed out, or haven't followed for long enough)
+    ///
+    /// Sending it again later can succeed
+    TemporarilyRejected,
+    /// A message or whisper was rejected (e.g. the user is banned, or the room is in subscribers-only mode)
+    SendRejected,
+    /// Room modes, such as slow mode or emote-only mode
+    RoomMode,
+    /// Bans and timeouts
+    BanTimeout,
+    /// Moderators and VIPs
+    Roles,
+    /// Hosting
+    Host,
+    /// Raids
+    Raid,
+    /// Other chat commands
+    Command,
+    /// The credentials used to register were rejected
+    Auth,
+    /// An unknown notice
+    Unknown,
+}
+
+/// Whether a [`NoticeKind`] reports a success, an error, usage help or information
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[non_exhaustive]
+pub enum NoticeOutcome {
+    /// An action succeeded
+    Success,
+    /// An action failed
+    Error,
+    /// The usage help for a command
+    Usage,
+    /// Information that isn't the result of an action
+    Info,
+    /// An unknown notice
+    Unknown,
+}
+
 impl<'a> TryFrom<Message<'a>> for Notice<'a> {
     type Error = Message<'a>;
 
@@ -425,4 +477,151 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn auth_failure() {
+        for (input, kind) in [
+            (
+                ":tmi.twitch.tv NOTICE * :Login authentication failed\r\n",
+                NoticeKind::LoginAuthenticationFailed,
+            ),
+            (
+                ":tmi.twitch.tv NOTICE * :Improperly formatted auth\r\n",
+                NoticeKind::ImproperlyFormattedAuth,
+            ),
+            (
+                ":tmi.twitch.tv NOTICE * :Login unsuccessful\r\n",
+                NoticeKind::LoginUnsuccessful,
+            ),
+        ] {
+            let notice = test_util::parse_as::<Notice>(input);
+            assert_eq!(notice.channel, "*");
+            assert_eq!(notice.notice_kind(), kind);
+            assert!(kind.is_auth_failure());
+        }
+
+        // a msg-id takes priority
+        let input = "@msg-id=tos_ban :tmi.twitch.tv NOTICE #museun :Login unsuccessful\r\n";
+        let notice = test_util::parse_as::<Notice>(input);
+        assert_eq!(notice.notice_kind(), NoticeKind::TosBan);
+        assert!(!notice.notice_kind().is_auth_failure());
+
+        let input = ":tmi.twitch.tv NOTICE * :something else\r\n";
+        let notice = test_util::parse_as::<Notice>(input);
+        assert_eq!(notice.notice_kind(), NoticeKind::Unknown);
+    }
+
+    #[test]
+    fn round_trip() {
+        for id in [
+            "msg_ratelimit",
+            "usage_ban",
+            "whisper_restricted",
+            "vip_success",
+        ] {
+            let kind = NoticeKind::from_msg_id(id);
+            assert!(!matches!(kind, NoticeKind::Other(..)), "{id}");
+            assert_eq!(kind.msg_id(), Some(id));
+            assert_eq!(id.parse(), Ok(kind));
+        }
+
+        let kind = NoticeKind::from_msg_id("some_new_notice");
+        assert_eq!(kind, NoticeKind::Other(Cow::from("some_new_notice")));
+        assert_eq!(kind.msg_id(), Some("some_new_notice"));
+        assert_eq!(kind.category(), NoticeCategory::Unknown);
+
+        assert_eq!(NoticeKind::LoginUnsuccessful.msg_id(), None);
+        assert_eq!(NoticeKind::Unknown.msg_id(), None);
+    }
+
+    #[test]
+    fn classification() {
+        for (id, category, outcome) in [
+            (
+                "msg_ratelimit",
+                NoticeCategory::RateLimit,
+                NoticeOutcome::Error,
+            ),
+            (
+                "msg_duplicate",
+                NoticeCategory::RateLimit,
+                NoticeOutcome::Error,
+            ),
+            (
+                "msg_banned",
+                NoticeCategory::SendRejected,
+                NoticeOutcome::Error,
+            ),
+            (
+                "msg_subsonly",
+                NoticeCategory::SendRejected,
+                NoticeOutcome::Error,
+            ),
+            (
+                "msg_timedout",
+                NoticeCategory::TemporarilyRejected,
+                NoticeOutcome::Error,
+            ),
+            (
+                "msg_followersonly_followed",
+                NoticeCategory::TemporarilyRejected,
+                NoticeOutcome::Error,
+            ),
+            (
+                "msg_followersonly",
+                NoticeCategory::SendRejected,
+                NoticeOutcome::Error,
+            ),
+            ("slow_on", NoticeCategory::RoomMode, NoticeOutcome::Success),
+            (
+                "already_r9k_on",
+                NoticeCategory::RoomMode,
+                NoticeOutcome::Error,
+            ),
+            (
+                "usage_slow_on",
+                NoticeCategory::RoomMode,
+                NoticeOutcome::Usage,
+            ),
+            (
+                "timeout_success",
+                NoticeCategory::BanTimeout,
+                NoticeOutcome::Success,
+            ),
+            (
+                "bad_ban_mod",
+                NoticeCategory::BanTimeout,
+                NoticeOutcome::Error,
+            ),
+            ("room_mods", NoticeCategory::Roles, NoticeOutcome::Info),
+            (
+                "raid_error_self",
+                NoticeCategory::Raid,
+                NoticeOutcome::Error,
+            ),
+            (
+                "cmds_available",
+                NoticeCategory::Command,
+                NoticeOutcome::Info,
+            ),
+        ] {
+            let kind = NoticeKind::from_msg_id(id);
+            assert_eq!(kind.category(), category, "{id}");
+            assert_eq!(kind.outcome(), outcome, "{id}");
+            assert_eq!(
+                kind.is_retryable(),
+                matches!(
+                    category,
+                    NoticeCategory::RateLimit | NoticeCategory::TemporarilyRejected
+                ),
+                "{id}"
+            );
+        }
+
+        assert!(NoticeKind::from_msg_id("usage_ban").is_usage());
+        assert!(NoticeKind::from_msg_id("ban_success").is_success());
+        assert!(NoticeKind::from_msg_id("bad_ban_self").is_error());
+        assert!(NoticeKind::ImproperlyFormattedAuth.is_error());
+        assert_eq!(NoticeKind::Unknown.outcome(), NoticeOutcome::Unknown);
+    }
 }
@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use alloc::{borrow::Cow, borrow::ToOwned};
 
 use super::Message;
 
@@ -36,8 +36,8 @@ impl<'a, 'b> TryFrom<&'b Message<'a>> for Part<'a> {
                 .as_name_str()
                 .map(ToOwned::to_owned)
                 .map(Cow::from)
-                .ok_or_else(|| value)?,
-            channel: value.args.get(0).cloned().ok_or_else(|| value)?,
+                .ok_or(value)?,
+            channel: value.args.first().cloned().ok_or(value)?,
         })
     }
 }
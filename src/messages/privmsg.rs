@@ -1,7 +1,10 @@
-use std::borrow::Cow;
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
 
 use super::{IntoCow, Message, Prefix, Tags, UserType};
-use crate::{builders::PrivmsgBuilder, parse_badges, Badge, Color, Emote};
+use crate::{
+    builders::PrivmsgBuilder, known_tags::KnownTag, parse_badges, parse_cheermotes, Badge,
+    Cheermote, Color, Emote, Fragment, Timestamp,
+};
 
 /// A user posts a message to the chat room.
 ///
@@ -32,7 +35,7 @@ impl<'a> Privmsg<'a> {
     /// Contains metadata related to the chat badges in the [`badges`](Self::badges) tag.
     pub fn badge_info<'t: 'a>(&'t self) -> impl Iterator<Item = Badge<'a>> + 't {
         self.tags
-            .get("badge-info")
+            .get(KnownTag::BadgeInfo)
             .into_iter()
             .flat_map(parse_badges)
     }
@@ -49,7 +52,79 @@ impl<'a> Privmsg<'a> {
 
     /// The amount of Bits the user cheered, if the message was a Bits cheer.
     pub fn bits(&self) -> Option<usize> {
-        self.tags.parsed("bits").transpose().ok().flatten()
+        self.tags.parsed(KnownTag::Bits).transpose().ok().flatten()
+    }
+
+    /// The cheermotes in the message, using the [`DEFAULT_CHEERMOTE_PREFIXES`](crate::DEFAULT_CHEERMOTE_PREFIXES)
+    ///
+    /// See [`cheermotes_with`](Self::cheermotes_with) to provide your own prefixes.
+    pub fn cheermotes(&self) -> impl Iterator<Item = Cheermote<'_>> + '_ {
+        self.cheermotes_with(crate::DEFAULT_CHEERMOTE_PREFIXES)
+    }
+
+    /// The cheermotes in the message, using these cheermote `prefixes`
+    ///
+    /// ```rust
+    /// # use twitch_message::messages::Privmsg;
+    /// let input = "@bits=600 :museun!museun@museun.tmi.twitch.tv PRIVMSG #museun :Cheer100 nice PogChamp500\r\n";
+    /// let pm = twitch_message::parse_as::<Privmsg>(input)?;
+    ///
+    /// let amounts = pm.cheermotes_with(&["Cheer", "PogChamp"]).map(|c| c.amount).collect::<Vec<_>>();
+    /// assert_eq!(amounts, [100, 500]);
+    /// assert!(pm.cheermotes_match_bits(&["Cheer", "PogChamp"]));
+    ///
+    /// // the default list doesn't know about `PogChamp`
+    /// assert!(!pm.cheermotes_match_bits(twitch_message::DEFAULT_CHEERMOTE_PREFIXES));
+    /// # Ok::<(),Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn cheermotes_with<'t: 'p, 'p>(
+        &'t self,
+        prefixes: &'p [&'p str],
+    ) -> impl Iterator<Item = Cheermote<'t>> + 'p {
+        parse_cheermotes(&self.data, prefixes)
+    }
+
+    /// Determines whether the cheermotes found with these `prefixes` add up to the [`bits`](Self::bits)
+    ///
+    /// This is false if the message doesn't have a `bits` tag.
+    pub fn cheermotes_match_bits(&self, prefixes: &[&str]) -> bool {
+        let Some(bits) = self.bits() else {
+            return false;
+        };
+        let total = self
+            .cheermotes_with(prefixes)
+            .try_fold(0_u64, |total, cheer| total.checked_add(cheer.amount));
+        total == Some(bits as u64)
+    }
+
+    /// Split the message into [`Fragment`]s of text, emotes, mentions, cheermotes and links
+    ///
+    /// Cheermotes are only looked for if the message has a `bits` tag, using the [`DEFAULT_CHEERMOTE_PREFIXES`](crate::DEFAULT_CHEERMOTE_PREFIXES).
+    /// See [`fragments_with`](Self::fragments_with) to provide your own prefixes.
+    ///
+    /// ```rust
+    /// # use twitch_message::{messages::Privmsg, Fragment};
+    /// let input = "@bits=100;emotes=25:0-4 :museun!museun@museun.tmi.twitch.tv PRIVMSG #museun :Kappa @shaken_bot Cheer100\r\n";
+    /// let pm = twitch_message::parse_as::<Privmsg>(input)?;
+    ///
+    /// let fragments = pm.fragments();
+    /// assert!(matches!(fragments[0], Fragment::Emote(..)));
+    /// assert_eq!(fragments[1], Fragment::Text(" "));
+    /// assert_eq!(fragments[2], Fragment::Mention("shaken_bot".into()));
+    /// assert_eq!(fragments[3], Fragment::Text(" "));
+    /// assert_eq!(fragments[4], Fragment::Cheer { prefix: "Cheer", amount: 100 });
+    /// # Ok::<(),Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn fragments(&self) -> Vec<Fragment<'_>> {
+        self.fragments_with(crate::DEFAULT_CHEERMOTE_PREFIXES)
+    }
+
+    /// Split the message into [`Fragment`]s, using these cheermote `prefixes`
+    ///
+    /// Cheermotes are only looked for if the message has a `bits` tag.
+    pub fn fragments_with(&self, prefixes: &[&str]) -> Vec<Fragment<'_>> {
+        let prefixes = self.tags.contains_key(KnownTag::Bits).then_some(prefixes);
+        crate::fragments::fragments(&self.tags, &self.data, prefixes)
     }
 
     /// The color of the user’s name in the chat room. This may be [`None`] if it is never set.
@@ -59,13 +134,13 @@ impl<'a> Privmsg<'a> {
 
     /// The user’s display name
     pub fn display_name(&self) -> Option<&super::DisplayNameRef> {
-        self.tags.get("display-name").map(Into::into)
+        self.tags.get(KnownTag::DisplayName).map(Into::into)
     }
 
     /// unknown tag, see instead [`first_msg_from_user`](Self::first_msg_from_user)
     pub fn returning_chatter(&self) -> bool {
         self.tags
-            .get("returning-chatter")
+            .get(KnownTag::ReturningChatter)
             .map(|s| s == "1")
             .unwrap_or_default()
     }
@@ -73,111 +148,356 @@ impl<'a> Privmsg<'a> {
     /// Signifies if this is the users first message, ever, in the chat room.
     pub fn first_msg_from_user(&self) -> bool {
         self.tags
-            .get("first-msg")
+            .get(KnownTag::FirstMsg)
             .map(|s| s == "1")
             .unwrap_or_default()
     }
 
     /// The UNIX timestamp.
+    ///
+    /// See [`timestamp`](Self::timestamp) for a parsed version
     pub fn tmi_sent_ts(&self) -> Option<&str> {
-        self.tags.get("tmi-sent-ts")
+        self.tags.get(KnownTag::TmiSentTs)
+    }
+
+    /// When the message was sent, from the [`tmi_sent_ts`](Self::tmi_sent_ts)
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.tags.parsed(KnownTag::TmiSentTs)?.ok()
     }
 
     /// An ID that uniquely identifies the message.
     pub fn msg_id(&self) -> Option<&super::MsgIdRef> {
-        self.tags.get("id").map(Into::into)
+        self.tags.get(KnownTag::Id).map(Into::into)
     }
 
     /// An ID that identifies the chat room (channel).
     pub fn room_id(&self) -> Option<&str> {
-        self.tags.get("room-id")
+        self.tags.get(KnownTag::RoomId)
     }
 
     /// An ID that uniquely identifies the parent message that this message is replying to.
     pub fn reply_parent_msg_id(&self) -> Option<&super::MsgIdRef> {
-        self.tags.get("reply-parent-msg-id").map(Into::into)
+        self.tags.get(KnownTag::ReplyParentMsgId).map(Into::into)
     }
 
     /// An ID that identifies the sender of the parent message.
     pub fn reply_parent_user_id(&self) -> Option<&super::UserIdRef> {
-        self.tags.get("reply-parent-user-id").map(Into::into)
+        self.tags.get(KnownTag::ReplyParentUserId).map(Into::into)
     }
 
     /// The login name of the sender of the parent message.
     pub fn reply_parent_user_login(&self) -> Option<&super::UserNameRef> {
-        self.tags.get("reply-parent-user-login").map(Into::into)
+        self.tags
+            .get(KnownTag::ReplyParentUserLogin)
+            .map(Into::into)
     }
 
     /// The display name of the sender of the parent message.
     pub fn reply_parent_display_name(&self) -> Option<&super::DisplayNameRef> {
-        self.tags.get("reply-parent-display-name").map(Into::into)
+        self.tags
+            .get(KnownTag::ReplyParentDisplayName)
+            .map(Into::into)
     }
 
     /// The text of the parent message.
     pub fn reply_parent_msg_body(&self) -> Option<&str> {
-        self.tags.get("reply-parent-msg-body")
+        self.tags.get(KnownTag::ReplyParentMsgBody)
+    }
+
+    /// The message this is a reply to, and the thread it is in
+    ///
+    /// This is [`None`] if this message isn't a reply
+    ///
+    /// ```rust
+    /// # use twitch_message::messages::Privmsg;
+    /// let input = "@reply-parent-msg-id=b34ccfc7-4977-403a-8a94-33c6bac34fb8;reply-parent-user-login=ronni;reply-parent-display-name=Ronni;reply-parent-msg-body=hello\\sthere;reply-thread-parent-msg-id=a1b2;reply-thread-parent-user-login=museun :shaken_bot!shaken_bot@shaken_bot.tmi.twitch.tv PRIVMSG #museun :@Ronni !hello\r\n";
+    /// let pm = twitch_message::parse_as::<Privmsg>(input)?;
+    ///
+    /// let reply = pm.reply().unwrap();
+    /// assert_eq!(reply.parent.msg_id.as_str(), "b34ccfc7-4977-403a-8a94-33c6bac34fb8");
+    /// assert_eq!(reply.parent_body, Some("hello there"));
+    /// assert_eq!(reply.thread.msg_id.as_str(), "a1b2");
+    /// assert_eq!(reply.thread.user_login.unwrap().as_str(), "museun");
+    ///
+    /// assert_eq!(pm.data, "@Ronni !hello");
+    /// assert_eq!(pm.data_without_reply_mention(), "!hello");
+    /// # Ok::<(),Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn reply(&self) -> Option<ReplyInfo<'_>> {
+        let parent = ReplyMessage {
+            msg_id: self.reply_parent_msg_id()?,
+            user_id: self.reply_parent_user_id(),
+            user_login: self.reply_parent_user_login(),
+            display_name: self.reply_parent_display_name(),
+        };
+
+        // older replies don't have the thread tags, the parent is the start of the thread
+        let thread = match self.tags.get(KnownTag::ReplyThreadParentMsgId) {
+            Some(msg_id) => ReplyMessage {
+                msg_id: msg_id.into(),
+                user_id: self
+                    .tags
+                    .get(KnownTag::ReplyThreadParentUserId)
+                    .map(Into::into),
+                user_login: self
+                    .tags
+                    .get(KnownTag::ReplyThreadParentUserLogin)
+                    .map(Into::into),
+                display_name: self
+                    .tags
+                    .get(KnownTag::ReplyThreadParentDisplayName)
+                    .map(Into::into),
+            },
+            None => parent,
+        };
+
+        Some(ReplyInfo {
+            parent,
+            thread,
+            parent_body: self.reply_parent_msg_body(),
+        })
+    }
+
+    /// The [`data`](Self::data) without the `@name ` mention Twitch prepends to replies
+    ///
+    /// If this message isn't a reply, or doesn't start with the mention, this is the [`data`](Self::data).
+    pub fn data_without_reply_mention(&self) -> &str {
+        let Some(reply) = self.reply() else {
+            return &self.data;
+        };
+
+        let names = [
+            reply.parent.user_login.map(|s| s.as_str()),
+            reply.parent.display_name.map(|s| s.as_str()),
+        ];
+
+        let Some(mention) = self.data.strip_prefix('@') else {
+            return &self.data;
+        };
+
+        names
+            .into_iter()
+            .flatten()
+            .find_map(|name| {
+                let head = mention.get(..name.len())?;
+                let tail = mention[name.len()..].strip_prefix(' ')?;
+                head.eq_ignore_ascii_case(name).then_some(tail)
+            })
+            .unwrap_or(&self.data)
+    }
+
+    /// The paid (Hype Chat) details of this message
+    ///
+    /// This is [`None`] if this isn't a paid message, or if its amount, exponent or currency are malformed
+    ///
+    /// ```rust
+    /// # use twitch_message::messages::{Privmsg, PaidLevel};
+    /// let input = "@pinned-chat-paid-amount=500;pinned-chat-paid-currency=USD;pinned-chat-paid-exponent=2;pinned-chat-paid-level=ONE;pinned-chat-paid-is-system-message=0 :museun!museun@museun.tmi.twitch.tv PRIVMSG #museun :hello\r\n";
+    /// let pm = twitch_message::parse_as::<Privmsg>(input)?;
+    ///
+    /// let paid = pm.paid_message().unwrap();
+    /// assert_eq!(paid.amount, 500);
+    /// assert_eq!(paid.major_units(), 5);
+    /// assert_eq!(paid.minor_units(), 0);
+    /// assert_eq!(paid.currency, "USD");
+    /// assert_eq!(paid.level, PaidLevel::One);
+    /// assert_eq!(paid.to_string(), "5.00 USD");
+    /// # Ok::<(),Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn paid_message(&self) -> Option<PaidMessage<'_>> {
+        Some(PaidMessage {
+            amount: self.tags.parsed(KnownTag::PinnedChatPaidAmount)?.ok()?,
+            exponent: self.tags.parsed(KnownTag::PinnedChatPaidExponent)?.ok()?,
+            currency: self.tags.get(KnownTag::PinnedChatPaidCurrency)?,
+            level: self
+                .tags
+                .get(KnownTag::PinnedChatPaidLevel)
+                .map(|level| level.parse().unwrap_or(PaidLevel::Unknown))
+                .unwrap_or(PaidLevel::Unknown),
+            is_system_message: self.tags.bool(KnownTag::PinnedChatPaidIsSystemMessage),
+        })
+    }
+
+    /// Where this message came from, if it was delivered through a [shared chat](super::SharedChatOrigin) session
+    pub fn shared_chat(&self) -> Option<super::SharedChatOrigin<'_>> {
+        super::SharedChatOrigin::from_tags(&self.tags)
+    }
+
+    /// Determines whether this message was sent in the channel it was delivered to
+    ///
+    /// In a shared chat session the same message is delivered to every participating channel, this is only true for one of them.
+    pub fn is_from_this_channel(&self) -> bool {
+        super::shared_chat::is_from_this_channel(&self.tags)
     }
 
     /// The type of user.
     pub fn user_type(&self) -> UserType {
         self.tags
-            .get("user-type")
+            .get(KnownTag::UserType)
             .map(UserType::parse)
             .unwrap_or_default()
     }
 
     /// The user’s ID.
     pub fn user_id(&self) -> Option<&super::UserIdRef> {
-        self.tags.get("user-id").map(Into::into)
+        self.tags.get(KnownTag::UserId).map(Into::into)
     }
 
-    /// The message is from the broadcaster of the channel
-    pub fn is_from_broadcaster(&self) -> bool {
-        self.badges()
-            .any(|badge| badge.name.as_str() == "broadcaster")
+    /// A builder for constructing a [`Message`](crate::messages::Message) or [`Privmsg`](crate::messages::Privmsg)
+    pub fn builder() -> PrivmsgBuilder {
+        PrivmsgBuilder::default()
     }
+}
 
-    /// The message is from a moderator in the channel
-    pub fn is_from_moderator(&self) -> bool {
-        self.badges()
-            .any(|badge| badge.name.as_str() == "moderator")
-    }
+/// The reply information of a [`Privmsg`], see [`Privmsg::reply`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ReplyInfo<'a> {
+    /// The message being replied to
+    pub parent: ReplyMessage<'a>,
+    /// The message that started the reply thread
+    ///
+    /// This is the same as the [`parent`](Self::parent) when replying to the start of a thread.
+    pub thread: ReplyMessage<'a>,
+    /// The text of the parent message
+    pub parent_body: Option<&'a str>,
+}
+
+/// A message in a reply thread, see [`ReplyInfo`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ReplyMessage<'a> {
+    /// An ID that uniquely identifies the message
+    pub msg_id: &'a super::MsgIdRef,
+    /// An ID that identifies the sender of the message
+    pub user_id: Option<&'a super::UserIdRef>,
+    /// The login name of the sender of the message
+    pub user_login: Option<&'a super::UserNameRef>,
+    /// The display name of the sender of the message
+    pub display_name: Option<&'a super::DisplayNameRef>,
+}
 
-    /// The message is from a VIP in the channel
-    pub fn is_from_vip(&self) -> bool {
-        self.badges().any(|badge| badge.name.as_str() == "vip")
+/// A paid (Hype Chat) message, see [`Privmsg::paid_message`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PaidMessage<'a> {
+    /// The amount paid, in the minor units of the [`currency`](Self::currency) (e.g. cents)
+    pub amount: u64,
+    /// The number of decimal places in the [`currency`](Self::currency), see [`major_units`](Self::major_units) and [`minor_units`](Self::minor_units)
+    pub exponent: u32,
+    /// The ISO-4217 currency code (e.g. `USD`)
+    pub currency: &'a str,
+    /// The level of the message, this determines how long it is pinned for
+    pub level: PaidLevel,
+    /// The message was sent by Twitch on behalf of the user
+    pub is_system_message: bool,
+}
+
+impl<'a> PaidMessage<'a> {
+    /// The whole units of the amount paid (e.g. `5` for an [`amount`](Self::amount) of `525` with an [`exponent`](Self::exponent) of `2`)
+    pub fn major_units(&self) -> u64 {
+        self.split().0
     }
 
-    /// The message is from a subscriber of the channel
-    pub fn is_from_subscriber(&self) -> bool {
-        self.badges()
-            .any(|badge| badge.name.as_str() == "subscriber")
+    /// The fractional part of the amount paid, in minor units (e.g. `25` for an [`amount`](Self::amount) of `525` with an [`exponent`](Self::exponent) of `2`)
+    pub fn minor_units(&self) -> u64 {
+        self.split().1
     }
 
-    /// The message is from Twitch staff
-    pub fn is_from_staff(&self) -> bool {
-        self.badges().any(|badge| badge.name.as_str() == "staff")
+    fn split(&self) -> (u64, u64) {
+        // an amount can't reach a scale that doesn't fit in a u64, so it's all minor units
+        match 10_u64.checked_pow(self.exponent) {
+            Some(scale) => (self.amount / scale, self.amount % scale),
+            None => (0, self.amount),
+        }
     }
+}
+
+impl<'a> core::fmt::Display for PaidMessage<'a> {
+    /// Formats the exact decimal amount and the currency (e.g. `5.00 USD`)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.exponent == 0 {
+            return write!(f, "{} {}", self.amount, self.currency);
+        }
 
-    /// The message is from a turbo user
-    pub fn is_from_turbo(&self) -> bool {
-        self.badges().any(|badge| badge.name.as_str() == "turbo")
+        write!(
+            f,
+            "{whole}.{frac:0width$} {currency}",
+            whole = self.major_units(),
+            frac = self.minor_units(),
+            width = self.exponent as usize,
+            currency = self.currency
+        )
     }
+}
+
+/// The level of a [`PaidMessage`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum PaidLevel {
+    /// A level this crate doesn't know about
+    Unknown,
+    #[allow(missing_docs)]
+    One,
+    #[allow(missing_docs)]
+    Two,
+    #[allow(missing_docs)]
+    Three,
+    #[allow(missing_docs)]
+    Four,
+    #[allow(missing_docs)]
+    Five,
+    #[allow(missing_docs)]
+    Six,
+    #[allow(missing_docs)]
+    Seven,
+    #[allow(missing_docs)]
+    Eight,
+    #[allow(missing_docs)]
+    Nine,
+    #[allow(missing_docs)]
+    Ten,
+}
 
-    /// The message is from a global moderator
-    pub fn is_from_global_moderator(&self) -> bool {
-        self.badges()
-            .any(|badge| badge.name.as_str() == "global_mod")
+impl PaidLevel {
+    /// The value of the `pinned-chat-paid-level` tag for this level
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Unknown => "UNKNOWN",
+            Self::One => "ONE",
+            Self::Two => "TWO",
+            Self::Three => "THREE",
+            Self::Four => "FOUR",
+            Self::Five => "FIVE",
+            Self::Six => "SIX",
+            Self::Seven => "SEVEN",
+            Self::Eight => "EIGHT",
+            Self::Nine => "NINE",
+            Self::Ten => "TEN",
+        }
     }
+}
 
-    /// The message is from a admin
-    pub fn is_from_admin(&self) -> bool {
-        self.badges().any(|badge| badge.name.as_str() == "admin")
+impl core::fmt::Display for PaidLevel {
+    /// Formats the level as the `pinned-chat-paid-level` tag value
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
     }
+}
 
-    /// A builder for constructing a [`Message`](crate::messages::Message) or [`Privmsg`](crate::messages::Privmsg)
-    pub fn builder() -> PrivmsgBuilder {
-        PrivmsgBuilder::default()
+impl core::str::FromStr for PaidLevel {
+    type Err = &'static str;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(match input {
+            "ONE" => Self::One,
+            "TWO" => Self::Two,
+            "THREE" => Self::Three,
+            "FOUR" => Self::Four,
+            "FIVE" => Self::Five,
+            "SIX" => Self::Six,
+            "SEVEN" => Self::Seven,
+            "EIGHT" => Self::Eight,
+            "NINE" => Self::Nine,
+            "TEN" => Self::Ten,
+            _ => return Err("unknown paid level"),
+        })
     }
 }
 
@@ -313,4 +633,83 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn reply() {
+        let input = "@reply-parent-display-name=Ronni;reply-parent-msg-body=hello\\sthere;reply-parent-msg-id=b34ccfc7;reply-parent-user-id=12345;reply-parent-user-login=ronni :museun!museun@museun.tmi.twitch.tv PRIVMSG #museun :@ronni !hello world\r\n";
+        let pm = test_util::parse_as::<Privmsg>(input);
+
+        let reply = pm.reply().unwrap();
+        let parent = ReplyMessage {
+            msg_id: "b34ccfc7".into(),
+            user_id: Some("12345".into()),
+            user_login: Some("ronni".into()),
+            display_name: Some("Ronni".into()),
+        };
+        assert_eq!(
+            reply,
+            ReplyInfo {
+                parent,
+                thread: parent,
+                parent_body: Some("hello there"),
+            }
+        );
+        assert_eq!(pm.data_without_reply_mention(), "!hello world");
+
+        // not a mention of the parent
+        let input = "@reply-parent-msg-id=b34ccfc7;reply-parent-user-login=ronni :museun!museun@museun.tmi.twitch.tv PRIVMSG #museun :@ronnie hi\r\n";
+        let pm = test_util::parse_as::<Privmsg>(input);
+        assert_eq!(pm.data_without_reply_mention(), "@ronnie hi");
+
+        // not a reply
+        let input = ":museun!museun@museun.tmi.twitch.tv PRIVMSG #museun :@ronni hi\r\n";
+        let pm = test_util::parse_as::<Privmsg>(input);
+        assert_eq!(pm.reply(), None);
+        assert_eq!(pm.data_without_reply_mention(), "@ronni hi");
+    }
+
+    #[test]
+    fn paid_message() {
+        let input = "@pinned-chat-paid-amount=1234;pinned-chat-paid-currency=JPY;pinned-chat-paid-exponent=0;pinned-chat-paid-level=TEN;pinned-chat-paid-is-system-message=1 :museun!museun@museun.tmi.twitch.tv PRIVMSG #museun :hello\r\n";
+        let pm = test_util::parse_as::<Privmsg>(input);
+        let paid = pm.paid_message().unwrap();
+        assert_eq!(
+            paid,
+            PaidMessage {
+                amount: 1234,
+                exponent: 0,
+                currency: "JPY",
+                level: PaidLevel::Ten,
+                is_system_message: true,
+            }
+        );
+        assert_eq!((paid.major_units(), paid.minor_units()), (1234, 0));
+        assert_eq!(paid.to_string(), "1234 JPY");
+
+        let paid = PaidMessage {
+            amount: 1005,
+            exponent: 2,
+            currency: "EUR",
+            level: PaidLevel::Unknown,
+            is_system_message: false,
+        };
+        assert_eq!((paid.major_units(), paid.minor_units()), (10, 5));
+        assert_eq!(paid.to_string(), "10.05 EUR");
+
+        let paid = PaidMessage {
+            amount: 5,
+            exponent: 25,
+            ..paid
+        };
+        assert_eq!((paid.major_units(), paid.minor_units()), (0, 5));
+        assert_eq!(paid.to_string(), "0.0000000000000000000000005 EUR");
+
+        let input = "@pinned-chat-paid-amount=abc;pinned-chat-paid-currency=USD;pinned-chat-paid-exponent=2 :museun!museun@museun.tmi.twitch.tv PRIVMSG #museun :hello\r\n";
+        let pm = test_util::parse_as::<Privmsg>(input);
+        assert_eq!(pm.paid_message(), None);
+
+        let input = ":museun!museun@museun.tmi.twitch.tv PRIVMSG #museun :hello\r\n";
+        let pm = test_util::parse_as::<Privmsg>(input);
+        assert_eq!(pm.paid_message(), None);
+    }
 }
@@ -1,4 +1,6 @@
-use std::borrow::Cow;
+use crate::known_tags::KnownTag;
+use alloc::borrow::Cow;
+use core::time::Duration;
 
 use super::{Message, Tags, UserIdRef};
 
@@ -17,32 +19,180 @@ pub struct RoomState<'a> {
 impl<'a> RoomState<'a> {
     /// Room is "emote" only
     pub fn emote_only(&self) -> bool {
-        self.tags.bool("emote-only")
+        self.tags.bool(KnownTag::EmoteOnly)
     }
 
     /// Room is followers only. The value indicates how long, in minutes, the user must have followed the broadcaster before posting chat messages.
     pub fn followers_only(&self) -> Option<usize> {
-        self.tags.parsed("followers-only")?.ok()
+        self.tags.parsed(KnownTag::FollowersOnly)?.ok()
     }
 
     /// Room is r9k/unique only
     pub fn r9k(&self) -> bool {
-        self.tags.bool("r9k")
+        self.tags.bool(KnownTag::R9k)
     }
 
     /// An ID that identifies the chat room (channel).
     pub fn room_id(&self) -> Option<&UserIdRef> {
-        self.tags.get("room-id").map(Into::into)
+        self.tags.get(KnownTag::RoomId).map(Into::into)
     }
 
     /// Room is in slow mode. The value determines how long, in seconds, users must wait between sending messages.
     pub fn slow(&self) -> Option<usize> {
-        self.tags.parsed("slow")?.ok()
+        self.tags.parsed(KnownTag::Slow)?.ok()
     }
 
     /// Room is subscribers and moderators only.
     pub fn subs_only(&self) -> bool {
-        self.tags.bool("subs-only")
+        self.tags.bool(KnownTag::SubsOnly)
+    }
+
+    /// The room modes in this message
+    ///
+    /// When a setting changes, Twitch only sends the changed mode, so the others will be [`RoomMode::Unchanged`].
+    ///
+    /// ```rust
+    /// # use twitch_message::messages::{RoomState, RoomMode};
+    /// # use std::time::Duration;
+    /// let input = "@emote-only=0;followers-only=-1;r9k=0;room-id=23196011;slow=0;subs-only=0 :tmi.twitch.tv ROOMSTATE #museun\r\n";
+    /// let mut modes = twitch_message::parse_as::<RoomState>(input)?.modes();
+    /// assert_eq!(modes.followers_only, RoomMode::Off);
+    /// assert_eq!(modes.slow, RoomMode::Off);
+    ///
+    /// let input = "@room-id=23196011;slow=10 :tmi.twitch.tv ROOMSTATE #museun\r\n";
+    /// let update = twitch_message::parse_as::<RoomState>(input)?.modes();
+    /// assert_eq!(update.slow, RoomMode::On(Duration::from_secs(10)));
+    /// assert_eq!(update.followers_only, RoomMode::Unchanged);
+    ///
+    /// modes.apply(&update);
+    /// assert_eq!(modes.slow, RoomMode::On(Duration::from_secs(10)));
+    /// assert_eq!(modes.followers_only, RoomMode::Off);
+    /// # Ok::<(),Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn modes(&self) -> RoomModes {
+        RoomModes::from_tags(&self.tags)
+    }
+}
+
+/// The state of a room mode in a [`RoomState`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub enum RoomMode<T = ()> {
+    /// The mode wasn't included, so it didn't change
+    ///
+    /// This is also used if the value could not be parsed
+    #[default]
+    Unchanged,
+    /// The mode is disabled
+    Off,
+    /// The mode is enabled, with its parameter
+    On(T),
+}
+
+impl<T> RoomMode<T> {
+    /// Determines whether the mode is enabled
+    pub const fn is_on(&self) -> bool {
+        matches!(self, Self::On(..))
+    }
+
+    /// Determines whether the mode is disabled
+    pub const fn is_off(&self) -> bool {
+        matches!(self, Self::Off)
+    }
+
+    /// Determines whether the mode was left unchanged
+    pub const fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+
+    /// The parameter of the mode, if it's enabled
+    pub const fn parameter(&self) -> Option<&T> {
+        match self {
+            Self::On(param) => Some(param),
+            _ => None,
+        }
+    }
+
+    /// Replace this mode with `update`, unless `update` is [`RoomMode::Unchanged`]
+    pub fn apply(&mut self, update: Self) {
+        if !update.is_unchanged() {
+            *self = update;
+        }
+    }
+}
+
+/// The chat settings of a room, from a [`RoomState`]
+///
+/// See [`RoomState::modes`]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct RoomModes {
+    /// Only emotes can be sent
+    pub emote_only: RoomMode,
+    /// Only followers can chat. The parameter is how long the user must have followed the broadcaster before posting chat messages.
+    pub followers_only: RoomMode<Duration>,
+    /// Messages must be unique (r9k)
+    pub r9k: RoomMode,
+    /// Users must wait between sending messages. The parameter is how long they must wait.
+    pub slow: RoomMode<Duration>,
+    /// Only subscribers and moderators can chat
+    pub subs_only: RoomMode,
+}
+
+impl RoomModes {
+    /// Parse the room modes from these tags
+    pub fn from_tags(tags: &Tags<'_>) -> Self {
+        let flag = |key| match tags.get_raw(key) {
+            Some("1") => RoomMode::On(()),
+            Some("0") => RoomMode::Off,
+            _ => RoomMode::Unchanged,
+        };
+
+        // followers-only uses -1 for disabled, and 0 for any follower
+        let followers_only = match tags.get_raw(KnownTag::FollowersOnly) {
+            Some("-1") => RoomMode::Off,
+            Some(minutes) => minutes
+                .parse()
+                .ok()
+                .and_then(|minutes: u64| minutes.checked_mul(60))
+                .map(|secs| RoomMode::On(Duration::from_secs(secs)))
+                .unwrap_or_default(),
+            None => RoomMode::Unchanged,
+        };
+
+        let slow = match tags.get_raw(KnownTag::Slow).map(str::parse) {
+            Some(Ok(0)) => RoomMode::Off,
+            Some(Ok(secs)) => RoomMode::On(Duration::from_secs(secs)),
+            _ => RoomMode::Unchanged,
+        };
+
+        Self {
+            emote_only: flag(KnownTag::EmoteOnly),
+            followers_only,
+            r9k: flag(KnownTag::R9k),
+            slow,
+            subs_only: flag(KnownTag::SubsOnly),
+        }
+    }
+
+    /// Merge a partial `update` into these modes
+    ///
+    /// Modes that are [`RoomMode::Unchanged`] in the `update` are kept
+    pub fn apply(&mut self, update: &Self) {
+        self.emote_only.apply(update.emote_only);
+        self.followers_only.apply(update.followers_only);
+        self.r9k.apply(update.r9k);
+        self.slow.apply(update.slow);
+        self.subs_only.apply(update.subs_only);
+    }
+
+    /// Determines whether every mode is known (e.g. from the [`RoomState`] sent when joining a channel)
+    pub const fn is_complete(&self) -> bool {
+        !(self.emote_only.is_unchanged()
+            || self.followers_only.is_unchanged()
+            || self.r9k.is_unchanged()
+            || self.slow.is_unchanged()
+            || self.subs_only.is_unchanged())
     }
 }
 
@@ -67,7 +217,7 @@ impl<'a, 'b> TryFrom<&'b Message<'a>> for RoomState<'a> {
             raw: value.raw.clone(),
             channel: value
                 .args
-                .get(0)
+                .first()
                 .cloned()
                 .expect("channel attached to message"),
         })
@@ -94,6 +244,69 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn modes() {
+        let input = "@emote-only=0;followers-only=-1;r9k=0;room-id=23196011;slow=0;subs-only=0 :tmi.twitch.tv ROOMSTATE #museun\r\n";
+        let mut modes = test_util::parse_as::<RoomState>(input).modes();
+        assert!(modes.is_complete());
+        assert_eq!(
+            modes,
+            RoomModes {
+                emote_only: RoomMode::Off,
+                followers_only: RoomMode::Off,
+                r9k: RoomMode::Off,
+                slow: RoomMode::Off,
+                subs_only: RoomMode::Off,
+            }
+        );
+
+        for (tags, expected) in [
+            (
+                "followers-only=0",
+                RoomModes {
+                    followers_only: RoomMode::On(Duration::ZERO),
+                    ..RoomModes::default()
+                },
+            ),
+            (
+                "followers-only=10;emote-only=1",
+                RoomModes {
+                    emote_only: RoomMode::On(()),
+                    followers_only: RoomMode::On(Duration::from_secs(600)),
+                    ..RoomModes::default()
+                },
+            ),
+            (
+                "slow=30;r9k=1",
+                RoomModes {
+                    r9k: RoomMode::On(()),
+                    slow: RoomMode::On(Duration::from_secs(30)),
+                    ..RoomModes::default()
+                },
+            ),
+            ("slow=abc;subs-only=", RoomModes::default()),
+            ("followers-only=307445734561825862", RoomModes::default()),
+        ] {
+            let input = format!("@room-id=23196011;{tags} :tmi.twitch.tv ROOMSTATE #museun\r\n");
+            let update = test_util::parse_as::<RoomState>(&input).modes();
+            assert!(!update.is_complete());
+            assert_eq!(update, expected, "{tags}");
+        }
+
+        let input = "@room-id=23196011;slow=30;r9k=1 :tmi.twitch.tv ROOMSTATE #museun\r\n";
+        modes.apply(&test_util::parse_as::<RoomState>(input).modes());
+        assert_eq!(
+            modes,
+            RoomModes {
+                emote_only: RoomMode::Off,
+                followers_only: RoomMode::Off,
+                r9k: RoomMode::On(()),
+                slow: RoomMode::On(Duration::from_secs(30)),
+                subs_only: RoomMode::Off,
+            }
+        );
+    }
 }
 
 // "display-name": "museun",
@@ -0,0 +1,111 @@
+use crate::{known_tags::KnownTag, parse_badges, Badge, Tags};
+
+use super::{MsgIdRef, UserIdRef};
+
+/// Where a message in a [shared chat](https://help.twitch.tv/s/article/shared-chat) session came from
+///
+/// In a shared chat session a message is delivered to every participating channel, with the `source-*` tags describing the channel it was sent in.
+///
+/// ```rust
+/// # use twitch_message::messages::Privmsg;
+/// let input = "@badges=;room-id=12345;source-badges=moderator/1;source-id=abc-123;source-room-id=67890 :museun!museun@museun.tmi.twitch.tv PRIVMSG #museun :hello\r\n";
+/// let pm = twitch_message::parse_as::<Privmsg>(input)?;
+///
+/// let origin = pm.shared_chat().unwrap();
+/// assert_eq!(origin.room_id().as_str(), "67890");
+/// assert_eq!(origin.msg_id().unwrap().as_str(), "abc-123");
+/// assert_eq!(origin.badges().next().unwrap().name.as_str(), "moderator");
+///
+/// // this was sent in another channel
+/// assert!(!pm.is_from_this_channel());
+/// # Ok::<(),Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SharedChatOrigin<'a> {
+    room_id: &'a UserIdRef,
+    msg_id: Option<&'a MsgIdRef>,
+    badges: Option<&'a str>,
+    badge_info: Option<&'a str>,
+    source_only: bool,
+}
+
+impl<'a> SharedChatOrigin<'a> {
+    /// Get the shared chat origin from these tags
+    ///
+    /// This is [`None`] if there isn't a `source-room-id` tag
+    pub fn from_tags(tags: &'a Tags<'_>) -> Option<Self> {
+        Some(Self {
+            room_id: tags.get(KnownTag::SourceRoomId)?.into(),
+            msg_id: tags.get(KnownTag::SourceId).map(Into::into),
+            badges: tags.get(KnownTag::SourceBadges),
+            badge_info: tags.get(KnownTag::SourceBadgeInfo),
+            source_only: tags.bool(KnownTag::SourceOnly),
+        })
+    }
+
+    /// The ID of the channel the message was sent in
+    pub const fn room_id(&self) -> &'a UserIdRef {
+        self.room_id
+    }
+
+    /// The ID of the message in the channel it was sent in
+    pub const fn msg_id(&self) -> Option<&'a MsgIdRef> {
+        self.msg_id
+    }
+
+    /// The badges the user has in the channel the message was sent in
+    pub fn badges(&self) -> impl Iterator<Item = Badge<'a>> + 'a {
+        self.badges.into_iter().flat_map(parse_badges)
+    }
+
+    /// Metadata related to the [`badges`](Self::badges) the user has in the channel the message was sent in
+    pub fn badge_info(&self) -> impl Iterator<Item = Badge<'a>> + 'a {
+        self.badge_info.into_iter().flat_map(parse_badges)
+    }
+
+    /// The message was only delivered to the channel it was sent in
+    pub const fn is_source_only(&self) -> bool {
+        self.source_only
+    }
+
+    /// Determines whether the message was sent in this room
+    pub fn is_from(&self, room_id: &UserIdRef) -> bool {
+        self.room_id == room_id
+    }
+}
+
+/// Determines whether the message was sent in the room it was delivered to
+pub(super) fn is_from_this_channel(tags: &Tags<'_>) -> bool {
+    match SharedChatOrigin::from_tags(tags) {
+        Some(origin) => tags
+            .get(KnownTag::RoomId)
+            .is_some_and(|room_id| origin.is_from(room_id.into())),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parse as _;
+
+    #[test]
+    fn origin() {
+        let tags = Tags::parse(&mut "@room-id=12345;source-badge-info=subscriber/3;source-badges=subscriber/3,vip/1;source-id=abc;source-only=1;source-room-id=67890 ").unwrap();
+
+        let origin = SharedChatOrigin::from_tags(&tags).unwrap();
+        assert_eq!(origin.room_id().as_str(), "67890");
+        assert_eq!(origin.msg_id().map(|s| s.as_str()), Some("abc"));
+        assert_eq!(origin.badges().count(), 2);
+        assert_eq!(origin.badge_info().count(), 1);
+        assert!(origin.is_source_only());
+        assert!(!is_from_this_channel(&tags));
+
+        let tags = Tags::parse(&mut "@room-id=67890;source-room-id=67890 ").unwrap();
+        assert!(is_from_this_channel(&tags));
+
+        let tags = Tags::parse(&mut "@room-id=67890 ").unwrap();
+        assert_eq!(SharedChatOrigin::from_tags(&tags), None);
+        assert!(is_from_this_channel(&tags));
+    }
+}
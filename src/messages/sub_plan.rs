@@ -0,0 +1,180 @@
+use alloc::borrow::Cow;
+#[cfg(feature = "serde")]
+use alloc::string::String;
+
+use crate::IntoStatic;
+
+/// The tier of a subscription, as found in the `msg-param-sub-plan` tag
+///
+/// Plans are ordered by their tier, with [`Prime`](Self::Prime) below [`Tier1`](Self::Tier1) and unknown plans below both
+///
+/// # Examples
+/// ```rust
+/// # use twitch_message::messages::SubPlan;
+/// let plan = SubPlan::parse("2000");
+/// assert_eq!(plan, SubPlan::Tier2);
+/// assert_eq!(plan.to_string(), "2000");
+/// assert_eq!(plan.name(), "Tier 2");
+///
+/// assert!(SubPlan::Prime < SubPlan::Tier1);
+///
+/// // an unknown plan keeps its original value
+/// let plan = SubPlan::parse("4000");
+/// assert_eq!(plan, SubPlan::Other("4000".into()));
+/// assert_eq!(plan.to_string(), "4000");
+/// assert_eq!("4000".parse::<SubPlan>()?, plan);
+/// # Ok::<(),Box<dyn std::error::Error>>(())
+/// ```
+///
+/// It formats as the tag value, so it can be used with a [`TagsBuilder`](crate::builders::TagsBuilder):
+/// ```rust
+/// # use twitch_message::{known_tags::KnownTag, messages::SubPlan, Tags};
+/// let tags = Tags::builder()
+///     .add(KnownTag::MsgParamSubPlan, SubPlan::Tier3)
+///     .finish();
+/// assert_eq!(tags.parsed(KnownTag::MsgParamSubPlan), Some(Ok(SubPlan::Tier3)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SubPlan<'a> {
+    /// A plan this crate doesn't know about, with its original `msg-param-sub-plan` value
+    Other(Cow<'a, str>),
+    /// A Prime Gaming subscription (`Prime`)
+    Prime,
+    /// A tier 1 subscription (`1000`)
+    Tier1,
+    /// A tier 2 subscription (`2000`)
+    Tier2,
+    /// A tier 3 subscription (`3000`)
+    Tier3,
+}
+
+impl<'a> SubPlan<'a> {
+    /// Parse a `msg-param-sub-plan` value, an unknown value is kept as [`SubPlan::Other`]
+    pub fn parse(input: &'a str) -> Self {
+        match input {
+            "Prime" => Self::Prime,
+            "1000" => Self::Tier1,
+            "2000" => Self::Tier2,
+            "3000" => Self::Tier3,
+            plan => Self::Other(Cow::Borrowed(plan)),
+        }
+    }
+
+    /// The value of the `msg-param-sub-plan` tag for this plan
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Other(plan) => plan,
+            Self::Prime => "Prime",
+            Self::Tier1 => "1000",
+            Self::Tier2 => "2000",
+            Self::Tier3 => "3000",
+        }
+    }
+
+    /// A human readable name for this plan (e.g. "Tier 1")
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Other(..) => "Unknown",
+            Self::Prime => "Prime",
+            Self::Tier1 => "Tier 1",
+            Self::Tier2 => "Tier 2",
+            Self::Tier3 => "Tier 3",
+        }
+    }
+
+    /// The numeric tier of this plan, a Prime subscription is a tier 1 subscription
+    pub const fn tier(&self) -> Option<u8> {
+        match self {
+            Self::Other(..) => None,
+            Self::Prime | Self::Tier1 => Some(1),
+            Self::Tier2 => Some(2),
+            Self::Tier3 => Some(3),
+        }
+    }
+}
+
+impl<'a> core::fmt::Display for SubPlan<'a> {
+    /// Formats the plan as the `msg-param-sub-plan` tag value
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::str::FromStr for SubPlan<'static> {
+    type Err = core::convert::Infallible;
+    /// Parses a `msg-param-sub-plan` value, an unknown value is kept as [`SubPlan::Other`]
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(SubPlan::parse(input).into_static())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> ::serde::Serialize for SubPlan<'a> {
+    /// Serializes the plan as the `msg-param-sub-plan` tag value
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 'a> ::serde::Deserialize<'de> for SubPlan<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        let plan = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(match SubPlan::parse(&plan) {
+            SubPlan::Other(..) => Self::Other(Cow::Owned(plan)),
+            plan => plan.into_static(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for plan in [
+            SubPlan::Prime,
+            SubPlan::Tier1,
+            SubPlan::Tier2,
+            SubPlan::Tier3,
+        ] {
+            assert_eq!(plan.to_string().parse(), Ok(plan.clone()));
+        }
+        assert_eq!("prime".parse(), Ok(SubPlan::Other(Cow::from("prime"))));
+
+        for input in ["Prime", "1000", "4000", "prime"] {
+            assert_eq!(SubPlan::parse(input).to_string(), input);
+        }
+        assert_eq!(SubPlan::parse("3000"), SubPlan::Tier3);
+        assert_eq!(SubPlan::parse("4000"), SubPlan::Other(Cow::from("4000")));
+    }
+
+    #[test]
+    fn ordering() {
+        let mut plans = [
+            SubPlan::Tier3,
+            SubPlan::Prime,
+            SubPlan::Other(Cow::from("4000")),
+            SubPlan::Tier1,
+            SubPlan::Tier2,
+        ];
+        plans.sort();
+        assert_eq!(
+            plans,
+            [
+                SubPlan::Other(Cow::from("4000")),
+                SubPlan::Prime,
+                SubPlan::Tier1,
+                SubPlan::Tier2,
+                SubPlan::Tier3
+            ]
+        );
+    }
+}
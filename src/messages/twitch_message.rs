@@ -48,6 +48,10 @@ pub enum TwitchMessage<'a> {
     Join(Join<'a>),
     /// A user has left a channel
     Part(Part<'a>),
+    /// A list of users in a channel, sent after joining it
+    Names(Names<'a>),
+    /// The end of the [`Names`](Self::Names) list
+    EndOfNames(EndOfNames<'a>),
     /// A twitch chat message.
     ///
     /// This is a 'catchall' for when a message cannot be turned into a [`self::TwitchMessage`]
@@ -306,6 +310,34 @@ impl<'a, 'b: 'a> From<&'b ClearChat<'a>> for TwitchMessage<'a> {
     }
 }
 
+impl<'a> From<Names<'a>> for TwitchMessage<'a> {
+    #[inline]
+    fn from(ty: Names<'a>) -> Self {
+        Self::Names(ty)
+    }
+}
+
+impl<'a, 'b: 'a> From<&'b Names<'a>> for TwitchMessage<'a> {
+    #[inline]
+    fn from(ty: &'b Names<'a>) -> Self {
+        Self::Names(ty.clone())
+    }
+}
+
+impl<'a> From<EndOfNames<'a>> for TwitchMessage<'a> {
+    #[inline]
+    fn from(ty: EndOfNames<'a>) -> Self {
+        Self::EndOfNames(ty)
+    }
+}
+
+impl<'a, 'b: 'a> From<&'b EndOfNames<'a>> for TwitchMessage<'a> {
+    #[inline]
+    fn from(ty: &'b EndOfNames<'a>) -> Self {
+        Self::EndOfNames(ty.clone())
+    }
+}
+
 impl<'a> From<Message<'a>> for TwitchMessage<'a> {
     #[inline]
     fn from(ty: Message<'a>) -> Self {
@@ -342,7 +374,10 @@ mod tests {
 "@emote-only=0;followers-only=-1;r9k=0;room-id=23196011;slow=0;subs-only=0 :tmi.twitch.tv ROOMSTATE #museun\r\n",
 ":museun!museun@museun.tmi.twitch.tv PRIVMSG #museun :hello world\r\n",
 "@room-id=12345678;tmi-sent-ts=1642715695392 :museun!museun@museun.tmi.twitch.tv WHISPER shaken_bot :this is a test\r\n",
-"@badge-info=;badges=premium/1;color=#008000;display-name=museun;emote-sets=0,19194,300374282,300597048,301337952,460515209,537206155,564265402,592920959,610186276;user-id=23196011;user-type= :tmi.twitch.tv GLOBALUSERSTATE\r\n"
+"@badge-info=;badges=premium/1;color=#008000;display-name=museun;emote-sets=0,19194,300374282,300597048,301337952,460515209,537206155,564265402,592920959,610186276;user-id=23196011;user-type= :tmi.twitch.tv GLOBALUSERSTATE\r\n",
+":museun.tmi.twitch.tv 353 museun = #museun :museun shaken_bot\r\n",
+":museun.tmi.twitch.tv 366 museun #museun :End of /NAMES list\r\n",
+":tmi.twitch.tv 375 museun :-\r\n",
         ];
 
         let expected = vec![
@@ -416,6 +451,25 @@ mod tests {
                 tags: Tags::default(),
                 raw: Cow::default(),
             }),
+            TwitchMessage::Names(Names {
+                name: Cow::Borrowed("".into()),
+                channel: Cow::default(),
+                names: Cow::default(),
+                raw: Cow::default(),
+            }),
+            TwitchMessage::EndOfNames(EndOfNames {
+                name: Cow::Borrowed("".into()),
+                channel: Cow::default(),
+                raw: Cow::default(),
+            }),
+            TwitchMessage::Message(Message {
+                raw: Cow::default(),
+                tags: Tags::default(),
+                prefix: Prefix::None,
+                kind: MessageKind::MotdStart,
+                args: vec![],
+                data: None,
+            }),
         ];
 
         let expected = expected.iter().map(std::mem::discriminant);
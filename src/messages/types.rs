@@ -0,0 +1,319 @@
+//! Strongly-typed string identifiers
+//!
+//! These mirror the types of the same names in [`twitch_types`](https://docs.rs/twitch_types), which does not support `no_std`.
+//!
+//! With the `twitch_types` feature, they can be converted to and from those types.
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::String,
+};
+
+/// Convert a type into a [`Cow`]
+pub trait IntoCow<'a, Ref: ?Sized>
+where
+    Ref: ToOwned,
+{
+    /// Make the cow with proper ownership
+    fn into_cow(self) -> Cow<'a, Ref>
+    where
+        &'a Self: 'a;
+}
+
+impl<'a, R, S> IntoCow<'a, R> for Cow<'a, S>
+where
+    R: ToOwned + ?Sized + 'a,
+    S: ToOwned + ?Sized + 'a,
+    S::Owned: Into<R::Owned>,
+    &'a R: From<&'a S>,
+{
+    fn into_cow(self) -> Cow<'a, R> {
+        match self {
+            Cow::Borrowed(b) => Cow::Borrowed(b.into()),
+            Cow::Owned(o) => Cow::Owned(o.into()),
+        }
+    }
+}
+
+impl<'a, R> IntoCow<'a, R> for &'a str
+where
+    &'a str: Into<&'a R>,
+    R: ToOwned + ?Sized + 'a,
+{
+    fn into_cow(self) -> Cow<'a, R> {
+        Cow::Borrowed(self.into())
+    }
+}
+
+impl<'a, R> IntoCow<'a, R> for String
+where
+    String: Into<R::Owned>,
+    R: ToOwned + ?Sized + 'a,
+{
+    fn into_cow(self) -> Cow<'a, R> {
+        Cow::Owned(self.into())
+    }
+}
+
+macro_rules! braid {
+    ($($(#[$meta:meta])* $owned:ident => $borrowed:ident;)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+            pub struct $owned(String);
+
+            impl $owned {
+                #[doc = concat!("Constructs a new ", stringify!($owned))]
+                pub const fn new(raw: String) -> Self {
+                    Self(raw)
+                }
+
+                /// Unwraps the underlying [`String`] value
+                pub fn take(self) -> String {
+                    self.0
+                }
+            }
+
+            impl From<String> for $owned {
+                fn from(raw: String) -> Self {
+                    Self(raw)
+                }
+            }
+
+            impl From<&str> for $owned {
+                fn from(raw: &str) -> Self {
+                    Self(raw.into())
+                }
+            }
+
+            impl From<$owned> for String {
+                fn from(owned: $owned) -> Self {
+                    owned.0
+                }
+            }
+
+            impl core::borrow::Borrow<$borrowed> for $owned {
+                fn borrow(&self) -> &$borrowed {
+                    self
+                }
+            }
+
+            impl core::ops::Deref for $owned {
+                type Target = $borrowed;
+
+                fn deref(&self) -> &Self::Target {
+                    $borrowed::from_str(&self.0)
+                }
+            }
+
+            impl core::fmt::Debug for $owned {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    <str as core::fmt::Debug>::fmt(&self.0, f)
+                }
+            }
+
+            impl core::fmt::Display for $owned {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str(&self.0)
+                }
+            }
+
+            $(#[$meta])*
+            #[repr(transparent)]
+            #[derive(Hash, PartialEq, Eq, PartialOrd, Ord)]
+            pub struct $borrowed(str);
+
+            impl $borrowed {
+                #[doc = concat!("Transparently reinterprets the string slice as a ", stringify!($borrowed))]
+                #[allow(clippy::should_implement_trait)]
+                pub const fn from_str(raw: &str) -> &Self {
+                    // SAFETY: this is `#[repr(transparent)]` around a single `str`
+                    unsafe { &*(raw as *const str as *const Self) }
+                }
+
+                /// Provides access to the underlying value as a string slice
+                pub const fn as_str(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl<'a> From<&'a str> for &'a $borrowed {
+                fn from(raw: &'a str) -> Self {
+                    $borrowed::from_str(raw)
+                }
+            }
+
+            impl<'a, 'b: 'a> From<&'a Cow<'b, $borrowed>> for &'a $borrowed {
+                fn from(raw: &'a Cow<'b, $borrowed>) -> Self {
+                    raw
+                }
+            }
+
+            impl ToOwned for $borrowed {
+                type Owned = $owned;
+
+                fn to_owned(&self) -> Self::Owned {
+                    $owned(self.0.into())
+                }
+            }
+
+            impl AsRef<str> for $borrowed {
+                fn as_ref(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl core::fmt::Debug for $borrowed {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    <str as core::fmt::Debug>::fmt(&self.0, f)
+                }
+            }
+
+            impl core::fmt::Display for $borrowed {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str(&self.0)
+                }
+            }
+
+            #[cfg(feature = "twitch_types")]
+            impl From<::twitch_types::$owned> for $owned {
+                fn from(owned: ::twitch_types::$owned) -> Self {
+                    Self(owned.take())
+                }
+            }
+
+            #[cfg(feature = "twitch_types")]
+            impl From<$owned> for ::twitch_types::$owned {
+                fn from(owned: $owned) -> Self {
+                    Self::new(owned.0)
+                }
+            }
+
+            #[cfg(feature = "twitch_types")]
+            impl<'a> From<&'a ::twitch_types::$borrowed> for &'a $borrowed {
+                fn from(borrowed: &'a ::twitch_types::$borrowed) -> Self {
+                    $borrowed::from_str(borrowed.as_str())
+                }
+            }
+
+            #[cfg(feature = "twitch_types")]
+            impl<'a> From<&'a $borrowed> for &'a ::twitch_types::$borrowed {
+                fn from(borrowed: &'a $borrowed) -> Self {
+                    ::twitch_types::$borrowed::from_str(&borrowed.0)
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl ::serde::Serialize for $owned {
+                fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_str(&self.0)
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl<'de> ::serde::Deserialize<'de> for $owned {
+                fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    <String as ::serde::Deserialize>::deserialize(deserializer).map(Self)
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl ::serde::Serialize for $borrowed {
+                fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_str(&self.0)
+                }
+            }
+        )*
+    };
+}
+
+braid! {
+    /// A user ID.
+    UserId => UserIdRef;
+    /// A users display name
+    DisplayName => DisplayNameRef;
+    /// A nickname, not capitalized.
+    Nickname => NicknameRef;
+    /// A message ID
+    MsgId => MsgIdRef;
+    /// A channel chat badge set ID
+    BadgeSetId => BadgeSetIdRef;
+    /// A channel chat badge ID
+    ChatBadgeId => ChatBadgeIdRef;
+    /// An emote ID
+    EmoteId => EmoteIdRef;
+    /// An emote set ID
+    EmoteSetId => EmoteSetIdRef;
+}
+
+/// A username, also specified as login. Should not be capitalized.
+pub type UserName = Nickname;
+
+/// A reference to a borrowed [`UserName`], also specified as login. Should not be capitalized.
+pub type UserNameRef = NicknameRef;
+
+/// User types: "staff", "admin", "global_mod", or "".
+#[derive(Copy, Clone, Default, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Deserialize))]
+pub enum UserType {
+    /// Staff
+    #[cfg_attr(feature = "serde", serde(rename = "staff"))]
+    Staff,
+    /// Admin
+    #[cfg_attr(feature = "serde", serde(rename = "admin"))]
+    Admin,
+    /// Global Moderator
+    #[cfg_attr(feature = "serde", serde(rename = "global_mod"))]
+    GlobalMod,
+    /// None
+    #[cfg_attr(feature = "serde", serde(other))]
+    #[default]
+    None,
+}
+
+impl UserType {
+    /// Parse a string into a [`UserType`]
+    pub fn parse(input: &str) -> Self {
+        match input {
+            "admin" => Self::Admin,
+            "global_mod" => Self::GlobalMod,
+            "staff" => Self::Staff,
+            _ => Self::None,
+        }
+    }
+}
+
+#[cfg(feature = "twitch_types")]
+impl From<::twitch_types::UserType> for UserType {
+    fn from(user_type: ::twitch_types::UserType) -> Self {
+        match user_type {
+            ::twitch_types::UserType::Staff => Self::Staff,
+            ::twitch_types::UserType::Admin => Self::Admin,
+            ::twitch_types::UserType::GlobalMod => Self::GlobalMod,
+            ::twitch_types::UserType::None => Self::None,
+        }
+    }
+}
+
+#[cfg(feature = "twitch_types")]
+impl From<UserType> for ::twitch_types::UserType {
+    fn from(user_type: UserType) -> Self {
+        match user_type {
+            UserType::Staff => Self::Staff,
+            UserType::Admin => Self::Admin,
+            UserType::GlobalMod => Self::GlobalMod,
+            UserType::None => Self::None,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ::serde::Serialize for UserType {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match self {
+            Self::Staff => "staff",
+            Self::Admin => "admin",
+            Self::GlobalMod => "global_mod",
+            Self::None => "",
+        })
+    }
+}
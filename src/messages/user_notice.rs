@@ -1,10 +1,10 @@
 #![allow(deprecated)]
 
-use std::borrow::Cow;
+use alloc::{borrow::Cow, vec::Vec};
 
-use crate::{parse_badges, Badge, Color, Emote, Tags};
+use crate::{known_tags::KnownTag, parse_badges, Badge, Color, Emote, Fragment, Tags, Timestamp};
 
-use super::{Message, UserType};
+use super::{Message, SubPlan, UserType};
 
 /// [`USERNOTICE`](https://dev.twitch.tv/docs/irc/commands/#usernotice). Sent when events like someone subscribing to the channel occurs.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -24,7 +24,7 @@ impl<'a> UserNotice<'a> {
     /// Contains metadata related to the chat badges in the [`badges`](Self::badges) tag.
     pub fn badge_info<'t: 'a>(&'t self) -> impl Iterator<Item = Badge<'a>> + 't {
         self.tags
-            .get("badge-info")
+            .get(KnownTag::BadgeInfo)
             .into_iter()
             .flat_map(parse_badges)
     }
@@ -41,6 +41,16 @@ impl<'a> UserNotice<'a> {
             .flat_map(|data| Emote::from_tags(&self.tags, data))
     }
 
+    /// Split the attached message into [`Fragment`]s of text, emotes, mentions and links
+    ///
+    /// This is empty if the notice doesn't have a message
+    pub fn fragments(&self) -> Vec<Fragment<'_>> {
+        self.data
+            .as_deref()
+            .map(|data| crate::fragments::fragments(&self.tags, data, None))
+            .unwrap_or_default()
+    }
+
     /// The color of the user’s name in the chat room. This may be [`None`] if it is never set.
     pub fn color(&self) -> Option<Color> {
         self.tags.color()
@@ -48,64 +58,84 @@ impl<'a> UserNotice<'a> {
 
     /// The user’s display name
     pub fn display_name(&self) -> Option<&super::DisplayNameRef> {
-        self.tags.get("display-name").map(Into::into)
+        self.tags.get(KnownTag::DisplayName).map(Into::into)
     }
 
     /// The message Twitch shows in the chat room for this notice.
     pub fn system_msg(&self) -> Option<&str> {
-        self.tags.get("system-msg")
+        self.tags.get(KnownTag::SystemMsg)
     }
 
     /// The user is a moderator in the channel.
     pub fn is_moderator(&self) -> bool {
-        self.tags.bool("mod")
+        self.tags.bool(KnownTag::Mod)
     }
 
     /// The user is a subscriber of the channel.
     pub fn is_subscriber(&self) -> bool {
-        self.tags.bool("subscriber")
+        self.tags.bool(KnownTag::Subscriber)
     }
 
     /// The user is a turbo user.
     pub fn is_turbo(&self) -> bool {
-        self.tags.bool("turbo")
+        self.tags.bool(KnownTag::Turbo)
     }
 
     /// The login name of the user whose action generated the message.
     pub fn login(&self) -> Option<&super::UserNameRef> {
-        self.tags.get("login").map(Into::into)
+        self.tags.get(KnownTag::Login).map(Into::into)
     }
 
     /// An ID that uniquely identifies this message.
     pub fn id(&self) -> Option<&super::MsgIdRef> {
-        self.tags.get("id").map(Into::into)
+        self.tags.get(KnownTag::Id).map(Into::into)
     }
 
     /// The type of notice
-    pub fn msg_id(&self) -> Option<UserNoticeId> {
-        self.tags.get("msg-id").map(UserNoticeId::parse)
+    ///
+    /// Notices this crate doesn't know about are kept as [`UserNoticeId::Other`]
+    pub fn msg_id(&self) -> Option<UserNoticeId<'_>> {
+        self.tags.get(KnownTag::MsgId).map(UserNoticeId::parse)
+    }
+
+    /// Where this message came from, if it was delivered through a [shared chat](super::SharedChatOrigin) session
+    pub fn shared_chat(&self) -> Option<super::SharedChatOrigin<'_>> {
+        super::SharedChatOrigin::from_tags(&self.tags)
+    }
+
+    /// Determines whether this message was sent in the channel it was delivered to
+    ///
+    /// In a shared chat session the same message is delivered to every participating channel, this is only true for one of them.
+    pub fn is_from_this_channel(&self) -> bool {
+        super::shared_chat::is_from_this_channel(&self.tags)
     }
 
     /// An ID that identifies the chat room (channel).
     pub fn room_id(&self) -> Option<&super::UserIdRef> {
-        self.tags.get("room-id").map(Into::into)
+        self.tags.get(KnownTag::RoomId).map(Into::into)
     }
 
     /// The user’s ID.
-
     pub fn user_id(&self) -> Option<&super::UserIdRef> {
-        self.tags.get("user-id").map(Into::into)
+        self.tags.get(KnownTag::UserId).map(Into::into)
     }
 
     /// The UNIX timestamp.
+    ///
+    /// See [`timestamp`](Self::timestamp) for a parsed version
     pub fn tmi_sent_ts(&self) -> Option<&str> {
-        self.tags.get("tmi-sent-ts")
+        self.tags.get(KnownTag::TmiSentTs)
+    }
+
+    /// When the message was sent, from the [`tmi_sent_ts`](Self::tmi_sent_ts)
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.tags.parsed(KnownTag::TmiSentTs)?.ok()
     }
 
     /// The user’s type.
     pub fn user_type(&self) -> UserType {
         self.tags
-            .get("user-type")
+            .get(KnownTag::UserType)
             .map(UserType::parse)
             .unwrap_or_default()
     }
@@ -114,7 +144,7 @@ impl<'a> UserNotice<'a> {
     ///
     /// Included only with [`sub`](UserNoticeId::Sub) and [`resub`](UserNoticeId::Resub) notices
     pub fn msg_param_cumulative_months(&self) -> Option<&str> {
-        self.tags.get("msg-param-cumulative-months")
+        self.tags.get(KnownTag::MsgParamCumulativeMonths)
     }
 
     /// The display name of the broadcaster raiding this channel.
@@ -123,8 +153,8 @@ impl<'a> UserNotice<'a> {
     pub fn msg_param_display_name(&self) -> Option<&super::DisplayNameRef> {
         // XXX: docs have this in a weird casing, going to try the other as well.
         self.tags
-            .get("msg-param-displayName")
-            .or_else(|| self.tags.get("msg-param-display-name"))
+            .get(KnownTag::MsgParamDisplayName)
+            .or_else(|| self.tags.get(KnownTag::MsgParamDisplayNameAlt))
             .map(Into::into)
     }
 
@@ -132,7 +162,7 @@ impl<'a> UserNotice<'a> {
     ///
     /// Included only with [`raid`](UserNoticeId::Raid) notices
     pub fn msg_param_login(&self) -> Option<&super::UserNameRef> {
-        self.tags.get("msg-param-login").map(Into::into)
+        self.tags.get(KnownTag::MsgParamLogin).map(Into::into)
     }
 
     /// The total number of months the user has subscribed.
@@ -143,21 +173,21 @@ impl<'a> UserNotice<'a> {
     ///
     /// [`msg-param-cumulative-months`](Self::msg_param_cumulative_months)
     pub fn msg_param_months(&self) -> Option<&str> {
-        self.tags.get("msg-param-months")
+        self.tags.get(KnownTag::MsgParamMonths)
     }
 
     /// The number of gifts the gifter has given during the promo indicated by [`msg-param-promo-name`](Self::msg_param_promo_name)
     ///
     /// Included only with [`anongiftpaidupgrade`](UserNoticeId::AnonGiftPaidUpgrade) and [`giftpaidupgrade`](UserNoticeId::GiftPaidUpgrade) notices
     pub fn msg_param_promo_gift_total(&self) -> Option<&str> {
-        self.tags.get("msg-param-promo-gift-total")
+        self.tags.get(KnownTag::MsgParamPromoGiftTotal)
     }
 
     /// The subscriptions promo, if any, that is ongoing (for example, Subtember 2018).
     ///
     /// Included only with [`anongiftpaidupgrade`](UserNoticeId::AnonGiftPaidUpgrade) and [`giftpaidupgrade`](UserNoticeId::GiftPaidUpgrade) notices
     pub fn msg_param_promo_name(&self) -> Option<&str> {
-        self.tags.get("msg-param-promo-name")
+        self.tags.get(KnownTag::MsgParamPromoName)
     }
 
     /// The display name of the subscription gift recipient.
@@ -165,7 +195,7 @@ impl<'a> UserNotice<'a> {
     /// Included only with [`subgift`](UserNoticeId::Subgift) notices
     pub fn msg_param_recipient_display_name(&self) -> Option<&super::DisplayNameRef> {
         self.tags
-            .get("msg-param-recipient-display-name")
+            .get(KnownTag::MsgParamRecipientDisplayName)
             .map(Into::into)
     }
 
@@ -173,7 +203,7 @@ impl<'a> UserNotice<'a> {
     ///
     /// Included only with [`subgift`](UserNoticeId::Subgift) notices
     pub fn msg_param_recipient_id(&self) -> Option<&super::UserIdRef> {
-        self.tags.get("msg-param-recipient-id").map(Into::into)
+        self.tags.get(KnownTag::MsgParamRecipientId).map(Into::into)
     }
 
     /// The user name of the subscription gift recipient.
@@ -181,7 +211,7 @@ impl<'a> UserNotice<'a> {
     /// Included only with [`subgift`](UserNoticeId::Subgift) notices
     pub fn msg_param_recipient_user_name(&self) -> Option<&super::UserNameRef> {
         self.tags
-            .get("msg-param-recipient-user-name")
+            .get(KnownTag::MsgParamRecipientUserName)
             .map(Into::into)
     }
 
@@ -189,153 +219,196 @@ impl<'a> UserNotice<'a> {
     ///
     /// Included only with [`giftpaidupgrade`](UserNoticeId::GiftPaidUpgrade) notices
     pub fn msg_param_sender_login(&self) -> Option<&super::UserNameRef> {
-        self.tags.get("msg-param-sender-login").map(Into::into)
+        self.tags.get(KnownTag::MsgParamSenderLogin).map(Into::into)
     }
 
     /// The display name of the user who gifted the subscription.
     ///
     /// Included only with [`giftpaidupgrade`](UserNoticeId::GiftPaidUpgrade) notices
     pub fn msg_param_sender_name(&self) -> Option<&super::DisplayNameRef> {
-        self.tags.get("msg-param-sender-name").map(Into::into)
+        self.tags.get(KnownTag::MsgParamSenderName).map(Into::into)
     }
 
     /// Indicates whether the user wants their streaks shared.
     ///
     /// Included only with [`sub`](UserNoticeId::Sub) and [`resub`](UserNoticeId::Resub) notices
     pub fn msg_param_should_share_streak(&self) -> Option<&str> {
-        self.tags.get("msg-param-should-share-streak")
+        self.tags.get(KnownTag::MsgParamShouldShareStreak)
     }
 
     /// The number of consecutive months the user has subscribed.
     ///
     /// Included only with [`sub`](UserNoticeId::Sub) and [`resub`](UserNoticeId::Resub) notices
     pub fn msg_param_streak_months(&self) -> Option<&str> {
-        self.tags.get("msg-param-streak-months")
+        self.tags.get(KnownTag::MsgParamStreakMonths)
     }
 
     /// The type of subscription plan being used.
     ///
     /// Included only with [`sub`](UserNoticeId::Sub), [`resub`](UserNoticeId::Resub) and [`subgift`](UserNoticeId::Subgift) notices
-    pub fn msg_param_sub_plan(&self) -> Option<&str> {
-        self.tags.get("msg-param-sub-plan")
+    ///
+    /// A plan this crate doesn't know about is returned as [`SubPlan::Other`]
+    pub fn msg_param_sub_plan(&self) -> Option<SubPlan<'_>> {
+        self.tags.get(KnownTag::MsgParamSubPlan).map(SubPlan::parse)
     }
 
     /// The display name of the subscription plan.
     ///
     /// Included only with [`sub`](UserNoticeId::Sub), [`resub`](UserNoticeId::Resub) and [`subgift`](UserNoticeId::Subgift) notices
     pub fn msg_param_sub_plan_name(&self) -> Option<&str> {
-        self.tags.get("msg-param-sub-plan-name")
+        self.tags.get(KnownTag::MsgParamSubPlanName)
     }
 
     /// The number of viewers raiding this channel from the broadcaster’s channel.
     ///
     /// Included only with [`raid`](UserNoticeId::Raid) notices
     pub fn msg_param_viewer_count(&self) -> Option<&str> {
-        self.tags.get("msg-param-viewerCount")
+        self.tags.get(KnownTag::MsgParamViewerCount)
     }
 
     #[deprecated]
     #[allow(missing_docs)]
     pub fn msg_param_ritual_name(&self) -> Option<&str> {
-        self.tags.get("msg-param-ritual-name")
+        self.tags.get(KnownTag::MsgParamRitualName)
     }
 
     /// The tier of the Bits badge the user just earned.
     ///
     /// Included only with [`bitsbadgetier`](UserNoticeId::BitsBadgeTier) notices
     pub fn msg_param_threshold(&self) -> Option<&str> {
-        self.tags.get("msg-param-threshold")
+        self.tags.get(KnownTag::MsgParamThreshold)
     }
 
     /// The number of months gifted as part of a single, multi-month gift.
     ///
     /// Included only with [`subgift`](UserNoticeId::Subgift) notices
     pub fn msg_param_gift_months(&self) -> Option<&str> {
-        self.tags.get("msg-param-gift-months")
+        self.tags.get(KnownTag::MsgParamGiftMonths)
     }
 
     /// The domain of the rewards being gifted (e.g. "pride_megacommerce_2020").
     ///
     /// Included only with [`rewardgift`](UserNoticeId::RewardGift) notices
     pub fn msg_param_domain(&self) -> Option<&str> {
-        self.tags.get("msg-param-domain")
+        self.tags.get(KnownTag::MsgParamDomain)
     }
 
     /// The type of monetary event that triggered the reward gift (e.g., "SUBGIFT", "CHEER").
     ///
     /// Included only with [`rewardgift`](UserNoticeId::RewardGift) notices
     pub fn msg_param_trigger_type(&self) -> Option<&str> {
-        self.tags.get("msg-param-trigger-type")
+        self.tags.get(KnownTag::MsgParamTriggerType)
     }
 
     /// The number of gifted rewards as part of the primary selection.
     ///
     /// Included only with [`rewardgift`](UserNoticeId::RewardGift) notices
     pub fn msg_param_selected_count(&self) -> Option<&str> {
-        self.tags.get("msg-param-selected-count")
+        self.tags.get(KnownTag::MsgParamSelectedCount)
     }
     /// The total number of rewards being gifted (e.g. 5 emotes).
     ///
     /// Included only with [`rewardgift`](UserNoticeId::RewardGift) notices
     pub fn msg_param_total_reward_count(&self) -> Option<&str> {
-        self.tags.get("msg-param-total-reward-count")
+        self.tags.get(KnownTag::MsgParamTotalRewardCount)
     }
     /// The number of instances of the trigger (e.g. 1 sub gift or 300 bits).
     ///
     /// Included only with [`rewardgift`](UserNoticeId::RewardGift) notices
     pub fn msg_param_trigger_amount(&self) -> Option<&str> {
-        self.tags.get("msg-param-trigger-amount")
+        self.tags.get(KnownTag::MsgParamTriggerAmount)
     }
 }
 
-/// The type of notice
-#[derive(Copy, Clone, Default, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
-#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
-pub enum UserNoticeId {
+macro_rules! user_notice_ids {
+    ($($(#[$meta:meta])* $ident:ident => $id:literal)*) => {
+        /// The type of notice
+        #[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[non_exhaustive]
+        pub enum UserNoticeId<'a> {
+            $(
+                $(#[$meta])*
+                $ident,
+            )*
+            /// A notice this crate doesn't know about, with its original `msg-id`
+            Other(Cow<'a, str>),
+        }
+
+        impl<'a> UserNoticeId<'a> {
+            /// The `msg-id` of this notice
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$ident => $id,)*
+                    Self::Other(id) => id,
+                }
+            }
+
+            pub(super) fn parse(input: &'a str) -> Self {
+                match input {
+                    $($id => Self::$ident,)*
+                    other => Self::Other(Cow::from(other)),
+                }
+            }
+        }
+    };
+}
+
+user_notice_ids! {
     /// A subscription event
-    Sub,
+    Sub => "sub"
     /// A resubscription event
-    Resub,
+    Resub => "resub"
     /// A gift subscription event
-    Subgift,
+    Subgift => "subgift"
+    /// A gift subscription from an anonymous user
+    AnonSubgift => "anonsubgift"
     /// A mass subscription event occurs.
-    SubMysteryGift,
+    SubMysteryGift => "submysterygift"
+    /// A mass subscription event from an anonymous user
+    AnonSubMysteryGift => "anonsubmysterygift"
     /// A gifted subscription is continued
-    GiftPaidUpgrade,
-    /// Monetary event triggered emotes to be shared
-    RewardGift,
+    GiftPaidUpgrade => "giftpaidupgrade"
     /// A gifted subscription from an anonymous user is continued
-    AnonGiftPaidUpgrade,
+    AnonGiftPaidUpgrade => "anongiftpaidupgrade"
+    /// A Prime subscription is converted to a paid subscription
+    PrimePaidUpgrade => "primepaidupgrade"
+    /// A user received a gift subscription from a Prime community gift
+    PrimeCommunityGiftReceived => "primecommunitygiftreceived"
+    /// A user extended their subscription with a subtember token
+    ExtendSub => "extendsub"
+    /// A user paid forward a gift subscription to a specific user
+    StandardPayForward => "standardpayforward"
+    /// A user paid forward a gift subscription to the community
+    CommunityPayForward => "communitypayforward"
+    /// Monetary event triggered emotes to be shared
+    RewardGift => "rewardgift"
     /// A raid happens
-    Raid,
+    Raid => "raid"
     /// A raid from the channel to another is cancelled.
-    Unraid,
+    Unraid => "unraid"
     #[allow(missing_docs)]
     #[deprecated]
-    Ritual,
+    Ritual => "ritual"
     /// A user shares a new bits badge
-    BitsBadgeTier,
-    /// Unknown notice
-    #[default]
-    Unknown,
+    BitsBadgeTier => "bitsbadgetier"
+    /// A moderator or broadcaster made an announcement
+    Announcement => "announcement"
+    /// A user reached a viewer milestone, such as a watch streak
+    ViewerMilestone => "viewermilestone"
+    /// A user donated to a charity campaign
+    CharityDonation => "charitydonation"
+    /// A user redeemed a one-tap gift (e.g. a combo)
+    OneTapGiftRedeemed => "onetapgiftredeemed"
+    /// A notice from another channel in a shared chat session
+    ///
+    /// The original `msg-id` is in the `source-msg-id` tag
+    SharedChatNotice => "sharedchatnotice"
 }
 
-impl UserNoticeId {
-    fn parse(input: &str) -> Self {
-        match input {
-            "sub" => Self::Sub,
-            "resub" => Self::Resub,
-            "subgift" => Self::Subgift,
-            "submysterygift" => Self::SubMysteryGift,
-            "giftpaidupgrade" => Self::GiftPaidUpgrade,
-            "rewardgift" => Self::RewardGift,
-            "anongiftpaidupgrade" => Self::AnonGiftPaidUpgrade,
-            "raid" => Self::Raid,
-            "unraid" => Self::Unraid,
-            "ritual" => Self::Ritual,
-            "bitsbadgetier" => Self::BitsBadgeTier,
-            _ => Self::Unknown,
-        }
+impl<'a> core::fmt::Display for UserNoticeId<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
     }
 }
 
@@ -411,4 +484,46 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn msg_id() {
+        for (id, expected) in [
+            ("sub", UserNoticeId::Sub),
+            ("announcement", UserNoticeId::Announcement),
+            ("viewermilestone", UserNoticeId::ViewerMilestone),
+            ("charitydonation", UserNoticeId::CharityDonation),
+            ("primepaidupgrade", UserNoticeId::PrimePaidUpgrade),
+            ("standardpayforward", UserNoticeId::StandardPayForward),
+            ("communitypayforward", UserNoticeId::CommunityPayForward),
+            ("onetapgiftredeemed", UserNoticeId::OneTapGiftRedeemed),
+            ("sharedchatnotice", UserNoticeId::SharedChatNotice),
+            (
+                "some-new-event",
+                UserNoticeId::Other(Cow::from("some-new-event")),
+            ),
+        ] {
+            let input = format!("@msg-id={id} :tmi.twitch.tv USERNOTICE #museun\r\n");
+            let notice = test_util::parse_as::<UserNotice>(&input);
+            let msg_id = notice.msg_id().unwrap();
+            assert_eq!(msg_id, expected);
+            assert_eq!(msg_id.as_str(), id);
+            assert_eq!(msg_id.to_string(), id);
+        }
+    }
+
+    #[test]
+    fn sub_plan() {
+        for (plan, expected) in [
+            ("Prime", SubPlan::Prime),
+            ("1000", SubPlan::Tier1),
+            ("3000", SubPlan::Tier3),
+            ("9000", SubPlan::Other(Cow::from("9000"))),
+        ] {
+            let input = format!(
+                "@msg-id=sub;msg-param-sub-plan={plan} :tmi.twitch.tv USERNOTICE #museun\r\n"
+            );
+            let notice = test_util::parse_as::<UserNotice>(&input);
+            assert_eq!(notice.msg_param_sub_plan(), Some(expected));
+        }
+    }
 }
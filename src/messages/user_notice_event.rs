@@ -0,0 +1,698 @@
+use alloc::{borrow::Cow, boxed::Box};
+
+use crate::{known_tags::KnownTag, Tags};
+
+use super::{DisplayNameRef, SubPlan, UserIdRef, UserNameRef, UserNotice, UserNoticeId};
+
+/// The typed payload of a [`UserNotice`], from its `msg-param-*` tags
+///
+/// This is produced by [`UserNotice::event`], with one variant per [`UserNoticeId`]
+///
+/// ```rust
+/// use twitch_message::messages::{UserNotice, UserNoticeEvent, SubPlan};
+///
+/// let input = "@login=museun;display-name=museun;user-id=23196011;msg-id=resub;msg-param-cumulative-months=6;msg-param-should-share-streak=0;msg-param-sub-plan=1000;msg-param-sub-plan-name=Channel\\sSubscription :tmi.twitch.tv USERNOTICE #museun\r\n";
+/// let notice = twitch_message::parse_as::<UserNotice>(input)?;
+///
+/// let UserNoticeEvent::Resub(sub) = notice.event()? else {
+///     unreachable!()
+/// };
+/// assert_eq!(sub.plan, SubPlan::Tier1);
+/// assert_eq!(sub.plan_name, Some("Channel Subscription"));
+/// assert_eq!(sub.cumulative_months, 6);
+/// assert_eq!(sub.streak_months, None);
+/// # Ok::<(),Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UserNoticeEvent<'a> {
+    /// A subscription event
+    Sub(Subscription<'a>),
+    /// A resubscription event
+    Resub(Subscription<'a>),
+    /// A gift subscription event
+    Subgift(SubGift<'a>),
+    /// A gift subscription from an anonymous user
+    AnonSubgift(SubGift<'a>),
+    /// A mass subscription event occurs.
+    SubMysteryGift(MysteryGift<'a>),
+    /// A mass subscription event from an anonymous user
+    AnonSubMysteryGift(MysteryGift<'a>),
+    /// A gifted subscription is continued
+    GiftPaidUpgrade {
+        /// The user who gifted the original subscription
+        gifter: EventUser<'a>,
+        /// The promotion, if the subscription was gifted during one
+        promo: Option<Promo<'a>>,
+    },
+    /// A gifted subscription from an anonymous user is continued
+    AnonGiftPaidUpgrade {
+        /// The promotion, if the subscription was gifted during one
+        promo: Option<Promo<'a>>,
+    },
+    /// A Prime subscription is converted to a paid subscription
+    PrimePaidUpgrade {
+        /// The plan the user upgraded to
+        plan: SubPlan<'a>,
+    },
+    /// A user received a gift subscription from a Prime community gift
+    PrimeCommunityGiftReceived,
+    /// A user extended their subscription with a subtember token
+    ExtendSub {
+        /// The subscription plan
+        plan: SubPlan<'a>,
+        /// The total number of months the user has subscribed
+        cumulative_months: u32,
+    },
+    /// A user paid forward a gift subscription to a specific user
+    StandardPayForward {
+        /// The user who gifted the original subscription, [`None`] if they were anonymous
+        prior_gifter: Option<EventUser<'a>>,
+        /// The user who received the gift
+        recipient: EventUser<'a>,
+    },
+    /// A user paid forward a gift subscription to the community
+    CommunityPayForward {
+        /// The user who gifted the original subscription, [`None`] if they were anonymous
+        prior_gifter: Option<EventUser<'a>>,
+    },
+    /// Monetary event triggered emotes to be shared
+    RewardGift {
+        /// The domain of the rewards being gifted (e.g. "pride_megacommerce_2020").
+        domain: &'a str,
+        /// The type of monetary event that triggered the reward gift (e.g., "SUBGIFT", "CHEER").
+        trigger_type: &'a str,
+        /// The number of instances of the trigger (e.g. 1 sub gift or 300 bits).
+        trigger_amount: u64,
+        /// The number of gifted rewards as part of the primary selection.
+        selected_count: u32,
+        /// The total number of rewards being gifted (e.g. 5 emotes).
+        total_reward_count: u32,
+    },
+    /// A raid happens
+    Raid {
+        /// The broadcaster raiding this channel
+        raider: EventUser<'a>,
+        /// The number of viewers raiding this channel
+        viewer_count: u64,
+    },
+    /// A raid from the channel to another is cancelled.
+    Unraid,
+    #[allow(missing_docs)]
+    #[deprecated]
+    Ritual { name: &'a str },
+    /// A user shares a new bits badge
+    BitsBadgeTier {
+        /// The tier of the Bits badge the user just earned
+        threshold: u64,
+    },
+    /// A moderator or broadcaster made an announcement
+    Announcement {
+        /// The highlight color of the announcement
+        color: AnnouncementColor<'a>,
+    },
+    /// A user reached a viewer milestone, such as a watch streak
+    ViewerMilestone {
+        /// The kind of milestone (e.g. "watch-streak")
+        category: &'a str,
+        /// The milestone that was reached (e.g. the length of the streak)
+        value: u32,
+        /// The channel points rewarded for the milestone
+        reward: Option<u64>,
+    },
+    /// A user donated to a charity campaign
+    CharityDonation {
+        /// The name of the charity
+        charity_name: &'a str,
+        /// The donation, in the minor units of the currency
+        amount: u64,
+        /// The ISO-4217 currency code
+        currency: &'a str,
+        /// The number of decimal places used by the currency
+        exponent: u32,
+    },
+    /// A user redeemed a one-tap gift (e.g. a combo)
+    OneTapGiftRedeemed {
+        /// The id of the gift
+        gift_id: &'a str,
+        /// The bits spent on the gift
+        bits_spent: u64,
+    },
+    /// A notice from another channel in a shared chat session
+    ///
+    /// The event is parsed from the `source-msg-id` tag
+    SharedChatNotice(Box<UserNoticeEvent<'a>>),
+    /// A notice this crate doesn't know about, with its original `msg-id`
+    Other(Cow<'a, str>),
+}
+
+/// A [`sub`](UserNoticeEvent::Sub) or [`resub`](UserNoticeEvent::Resub)
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Subscription<'a> {
+    /// The subscription plan
+    pub plan: SubPlan<'a>,
+    /// The display name of the subscription plan
+    pub plan_name: Option<&'a str>,
+    /// The total number of months the user has subscribed
+    pub cumulative_months: u32,
+    /// The number of consecutive months the user has subscribed, if they chose to share it
+    pub streak_months: Option<u32>,
+    /// The number of months purchased up front, for multi-month subscriptions
+    pub multimonth_duration: Option<u32>,
+}
+
+/// A [`subgift`](UserNoticeEvent::Subgift) or [`anonsubgift`](UserNoticeEvent::AnonSubgift)
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SubGift<'a> {
+    /// The user who gifted the subscription, [`None`] if they were anonymous
+    pub gifter: Option<EventUser<'a>>,
+    /// The user who received the subscription
+    pub recipient: EventUser<'a>,
+    /// The subscription plan
+    pub plan: SubPlan<'a>,
+    /// The display name of the subscription plan
+    pub plan_name: Option<&'a str>,
+    /// The number of months gifted as part of a single, multi-month gift
+    pub gift_months: u32,
+    /// The total number of months the recipient has subscribed
+    pub months: Option<u32>,
+}
+
+/// A [`submysterygift`](UserNoticeEvent::SubMysteryGift) or [`anonsubmysterygift`](UserNoticeEvent::AnonSubMysteryGift)
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MysteryGift<'a> {
+    /// The user who gifted the subscriptions, [`None`] if they were anonymous
+    pub gifter: Option<EventUser<'a>>,
+    /// The subscription plan
+    pub plan: SubPlan<'a>,
+    /// The number of subscriptions gifted
+    pub count: u32,
+    /// The total number of subscriptions the gifter has gifted in the channel
+    pub sender_count: Option<u32>,
+}
+
+/// A promotion that a subscription was gifted during
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Promo<'a> {
+    /// The name of the promotion (e.g. "Subtember 2018")
+    pub name: &'a str,
+    /// The number of gifts the gifter has given during the promotion
+    pub gift_total: Option<u32>,
+}
+
+/// A user mentioned by a [`UserNoticeEvent`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EventUser<'a> {
+    /// The user’s ID, if Twitch included it
+    pub id: Option<&'a UserIdRef>,
+    /// The user’s login name
+    pub login: &'a UserNameRef,
+    /// The user’s display name
+    pub display_name: Option<&'a DisplayNameRef>,
+}
+
+/// The highlight color of an [`announcement`](UserNoticeEvent::Announcement)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[non_exhaustive]
+pub enum AnnouncementColor<'a> {
+    /// The channel's accent color
+    #[default]
+    Primary,
+    #[allow(missing_docs)]
+    Blue,
+    #[allow(missing_docs)]
+    Green,
+    #[allow(missing_docs)]
+    Orange,
+    #[allow(missing_docs)]
+    Purple,
+    /// A color this crate doesn't know about, with its original `msg-param-color` value
+    Other(Cow<'a, str>),
+}
+
+impl<'a> AnnouncementColor<'a> {
+    fn parse(input: &'a str) -> Self {
+        match input {
+            "PRIMARY" => Self::Primary,
+            "BLUE" => Self::Blue,
+            "GREEN" => Self::Green,
+            "ORANGE" => Self::Orange,
+            "PURPLE" => Self::Purple,
+            color => Self::Other(Cow::Borrowed(color)),
+        }
+    }
+}
+
+/// Errors for [`UserNotice::event`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum UserNoticeEventError {
+    /// A required tag was missing
+    MissingTag(KnownTag),
+    /// A tag could not be parsed
+    InvalidTag(KnownTag),
+}
+
+impl core::fmt::Display for UserNoticeEventError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingTag(tag) => write!(f, "missing the `{tag}` tag"),
+            Self::InvalidTag(tag) => write!(f, "invalid value for the `{tag}` tag"),
+        }
+    }
+}
+
+impl core::error::Error for UserNoticeEventError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        None
+    }
+}
+
+// Twitch sends this as the gifter for anonymous gifts
+const ANONYMOUS_GIFTER: &str = "ananonymousgifter";
+
+struct Fields<'a> {
+    tags: &'a Tags<'a>,
+}
+
+impl<'a> Fields<'a> {
+    fn optional(&self, tag: KnownTag) -> Option<&'a str> {
+        self.tags.get(tag)
+    }
+
+    fn required(&self, tag: KnownTag) -> Result<&'a str, UserNoticeEventError> {
+        self.optional(tag)
+            .ok_or(UserNoticeEventError::MissingTag(tag))
+    }
+
+    fn parsed_opt<T: core::str::FromStr>(
+        &self,
+        tag: KnownTag,
+    ) -> Result<Option<T>, UserNoticeEventError> {
+        self.optional(tag)
+            .map(|value| value.parse())
+            .transpose()
+            .map_err(|_| UserNoticeEventError::InvalidTag(tag))
+    }
+
+    fn parsed<T: core::str::FromStr>(&self, tag: KnownTag) -> Result<T, UserNoticeEventError> {
+        self.parsed_opt(tag)?
+            .ok_or(UserNoticeEventError::MissingTag(tag))
+    }
+
+    fn plan(&self) -> Result<SubPlan<'a>, UserNoticeEventError> {
+        self.required(KnownTag::MsgParamSubPlan).map(SubPlan::parse)
+    }
+
+    fn user(
+        &self,
+        id: KnownTag,
+        login: KnownTag,
+        display_name: KnownTag,
+    ) -> Result<EventUser<'a>, UserNoticeEventError> {
+        Ok(EventUser {
+            id: self.optional(id).map(Into::into),
+            login: self.required(login)?.into(),
+            display_name: self.optional(display_name).map(Into::into),
+        })
+    }
+
+    fn sender(&self) -> Result<Option<EventUser<'a>>, UserNoticeEventError> {
+        let user = self.user(KnownTag::UserId, KnownTag::Login, KnownTag::DisplayName)?;
+        Ok(Some(user).filter(|user| user.login.as_str() != ANONYMOUS_GIFTER))
+    }
+
+    fn prior_gifter(&self) -> Result<Option<EventUser<'a>>, UserNoticeEventError> {
+        // XXX: this is sent as `true`/`false` rather than `1`/`0`
+        if matches!(
+            self.optional(KnownTag::MsgParamPriorGifterAnonymous),
+            Some("true" | "1")
+        ) {
+            return Ok(None);
+        }
+        self.user(
+            KnownTag::MsgParamPriorGifterId,
+            KnownTag::MsgParamPriorGifterUserName,
+            KnownTag::MsgParamPriorGifterDisplayName,
+        )
+        .map(Some)
+    }
+
+    fn promo(&self) -> Result<Option<Promo<'a>>, UserNoticeEventError> {
+        let Some(name) = self.optional(KnownTag::MsgParamPromoName) else {
+            return Ok(None);
+        };
+        Ok(Some(Promo {
+            name,
+            gift_total: self.parsed_opt(KnownTag::MsgParamPromoGiftTotal)?,
+        }))
+    }
+
+    fn subscription(&self) -> Result<Subscription<'a>, UserNoticeEventError> {
+        let streak_months = if self.tags.bool(KnownTag::MsgParamShouldShareStreak) {
+            self.parsed_opt(KnownTag::MsgParamStreakMonths)?
+        } else {
+            None
+        };
+
+        Ok(Subscription {
+            plan: self.plan()?,
+            plan_name: self.optional(KnownTag::MsgParamSubPlanName),
+            cumulative_months: self.parsed(KnownTag::MsgParamCumulativeMonths)?,
+            streak_months,
+            multimonth_duration: self.parsed_opt(KnownTag::MsgParamMultimonthDuration)?,
+        })
+    }
+
+    fn sub_gift(&self, anonymous: bool) -> Result<SubGift<'a>, UserNoticeEventError> {
+        Ok(SubGift {
+            gifter: if anonymous { None } else { self.sender()? },
+            recipient: self.user(
+                KnownTag::MsgParamRecipientId,
+                KnownTag::MsgParamRecipientUserName,
+                KnownTag::MsgParamRecipientDisplayName,
+            )?,
+            plan: self.plan()?,
+            plan_name: self.optional(KnownTag::MsgParamSubPlanName),
+            gift_months: self.parsed_opt(KnownTag::MsgParamGiftMonths)?.unwrap_or(1),
+            months: self.parsed_opt(KnownTag::MsgParamMonths)?,
+        })
+    }
+
+    fn mystery_gift(&self, anonymous: bool) -> Result<MysteryGift<'a>, UserNoticeEventError> {
+        Ok(MysteryGift {
+            gifter: if anonymous { None } else { self.sender()? },
+            plan: self.plan()?,
+            count: self.parsed(KnownTag::MsgParamMassGiftCount)?,
+            sender_count: self.parsed_opt(KnownTag::MsgParamSenderCount)?,
+        })
+    }
+
+    fn event(&self, id: UserNoticeId<'a>) -> Result<UserNoticeEvent<'a>, UserNoticeEventError> {
+        use UserNoticeEvent as E;
+        use UserNoticeId as Id;
+
+        let event = match id {
+            Id::Sub => E::Sub(self.subscription()?),
+            Id::Resub => E::Resub(self.subscription()?),
+            Id::Subgift => E::Subgift(self.sub_gift(false)?),
+            Id::AnonSubgift => E::AnonSubgift(self.sub_gift(true)?),
+            Id::SubMysteryGift => E::SubMysteryGift(self.mystery_gift(false)?),
+            Id::AnonSubMysteryGift => E::AnonSubMysteryGift(self.mystery_gift(true)?),
+            Id::GiftPaidUpgrade => E::GiftPaidUpgrade {
+                gifter: EventUser {
+                    id: None,
+                    login: self.required(KnownTag::MsgParamSenderLogin)?.into(),
+                    display_name: self.optional(KnownTag::MsgParamSenderName).map(Into::into),
+                },
+                promo: self.promo()?,
+            },
+            Id::AnonGiftPaidUpgrade => E::AnonGiftPaidUpgrade {
+                promo: self.promo()?,
+            },
+            Id::PrimePaidUpgrade => E::PrimePaidUpgrade { plan: self.plan()? },
+            Id::PrimeCommunityGiftReceived => E::PrimeCommunityGiftReceived,
+            Id::ExtendSub => E::ExtendSub {
+                plan: self.plan()?,
+                cumulative_months: self.parsed(KnownTag::MsgParamCumulativeMonths)?,
+            },
+            Id::StandardPayForward => E::StandardPayForward {
+                prior_gifter: self.prior_gifter()?,
+                recipient: self.user(
+                    KnownTag::MsgParamRecipientId,
+                    KnownTag::MsgParamRecipientUserName,
+                    KnownTag::MsgParamRecipientDisplayName,
+                )?,
+            },
+            Id::CommunityPayForward => E::CommunityPayForward {
+                prior_gifter: self.prior_gifter()?,
+            },
+            Id::RewardGift => E::RewardGift {
+                domain: self.required(KnownTag::MsgParamDomain)?,
+                trigger_type: self.required(KnownTag::MsgParamTriggerType)?,
+                trigger_amount: self.parsed(KnownTag::MsgParamTriggerAmount)?,
+                selected_count: self.parsed(KnownTag::MsgParamSelectedCount)?,
+                total_reward_count: self.parsed(KnownTag::MsgParamTotalRewardCount)?,
+            },
+            Id::Raid => E::Raid {
+                raider: EventUser {
+                    id: self.optional(KnownTag::UserId).map(Into::into),
+                    login: self.required(KnownTag::MsgParamLogin)?.into(),
+                    // XXX: docs have this in a weird casing, going to try the other as well.
+                    display_name: self
+                        .optional(KnownTag::MsgParamDisplayName)
+                        .or_else(|| self.optional(KnownTag::MsgParamDisplayNameAlt))
+                        .map(Into::into),
+                },
+                viewer_count: self.parsed(KnownTag::MsgParamViewerCount)?,
+            },
+            Id::Unraid => E::Unraid,
+            #[allow(deprecated)]
+            Id::Ritual => E::Ritual {
+                name: self.required(KnownTag::MsgParamRitualName)?,
+            },
+            Id::BitsBadgeTier => E::BitsBadgeTier {
+                threshold: self.parsed(KnownTag::MsgParamThreshold)?,
+            },
+            Id::Announcement => E::Announcement {
+                color: self
+                    .optional(KnownTag::MsgParamColor)
+                    .map(AnnouncementColor::parse)
+                    .unwrap_or_default(),
+            },
+            Id::ViewerMilestone => E::ViewerMilestone {
+                category: self.required(KnownTag::MsgParamCategory)?,
+                value: self.parsed(KnownTag::MsgParamValue)?,
+                reward: self.parsed_opt(KnownTag::MsgParamCopoReward)?,
+            },
+            Id::CharityDonation => E::CharityDonation {
+                charity_name: self.required(KnownTag::MsgParamCharityName)?,
+                amount: self.parsed(KnownTag::MsgParamDonationAmount)?,
+                currency: self.required(KnownTag::MsgParamDonationCurrency)?,
+                exponent: self.parsed(KnownTag::MsgParamExponent)?,
+            },
+            Id::OneTapGiftRedeemed => E::OneTapGiftRedeemed {
+                gift_id: self.required(KnownTag::MsgParamGiftId)?,
+                bits_spent: self.parsed(KnownTag::MsgParamBitsSpent)?,
+            },
+            Id::SharedChatNotice => {
+                let tag = KnownTag::SourceMsgId;
+                let id = UserNoticeId::parse(self.required(tag)?);
+                // a shared notice can't wrap another shared notice
+                if id == UserNoticeId::SharedChatNotice {
+                    return Err(UserNoticeEventError::InvalidTag(tag));
+                }
+                E::SharedChatNotice(Box::new(self.event(id)?))
+            }
+            Id::Other(id) => E::Other(id),
+        };
+        Ok(event)
+    }
+}
+
+impl<'a> UserNotice<'a> {
+    /// Parse the `msg-param-*` tags of this notice into a typed [`UserNoticeEvent`]
+    ///
+    /// This returns an error if the `msg-id` is missing, or if a tag required by the event is missing or malformed.
+    pub fn event(&self) -> Result<UserNoticeEvent<'_>, UserNoticeEventError> {
+        let fields = Fields { tags: &self.tags };
+        let id = fields.required(KnownTag::MsgId)?;
+        fields.event(UserNoticeId::parse(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_util, IntoStatic as _};
+
+    fn notice(tags: &str) -> UserNotice<'static> {
+        let input = format!("@{tags} :tmi.twitch.tv USERNOTICE #museun\r\n");
+        test_util::parse_as::<UserNotice>(&input).into_static()
+    }
+
+    #[test]
+    fn sub() {
+        let tags = "msg-id=sub;msg-param-cumulative-months=6;msg-param-should-share-streak=1;msg-param-streak-months=2;msg-param-sub-plan=Prime;msg-param-sub-plan-name=Prime";
+        assert_eq!(
+            notice(tags).event(),
+            Ok(UserNoticeEvent::Sub(Subscription {
+                plan: SubPlan::Prime,
+                plan_name: Some("Prime"),
+                cumulative_months: 6,
+                streak_months: Some(2),
+                multimonth_duration: None,
+            }))
+        );
+
+        let tags = "msg-id=resub;msg-param-cumulative-months=six;msg-param-sub-plan=1000";
+        assert_eq!(
+            notice(tags).event(),
+            Err(UserNoticeEventError::InvalidTag(
+                KnownTag::MsgParamCumulativeMonths
+            ))
+        );
+
+        let tags = "msg-id=resub;msg-param-cumulative-months=6;msg-param-sub-plan=4000";
+        assert_eq!(
+            notice(tags).event(),
+            Ok(UserNoticeEvent::Resub(Subscription {
+                plan: SubPlan::Other(Cow::from("4000")),
+                plan_name: None,
+                cumulative_months: 6,
+                streak_months: None,
+                multimonth_duration: None,
+            }))
+        );
+
+        let tags = "msg-id=resub;msg-param-sub-plan=1000";
+        assert_eq!(
+            notice(tags).event(),
+            Err(UserNoticeEventError::MissingTag(
+                KnownTag::MsgParamCumulativeMonths
+            ))
+        );
+    }
+
+    #[test]
+    fn gifts() {
+        let tags = "display-name=TWW2;login=tww2;user-id=87654321;msg-id=subgift;msg-param-months=1;msg-param-recipient-display-name=Mr_Woodchuck;msg-param-recipient-id=55554444;msg-param-recipient-user-name=mr_woodchuck;msg-param-sub-plan-name=House\\sof\\sNyoro~n;msg-param-sub-plan=1000";
+        assert_eq!(
+            notice(tags).event(),
+            Ok(UserNoticeEvent::Subgift(SubGift {
+                gifter: Some(EventUser {
+                    id: Some("87654321".into()),
+                    login: "tww2".into(),
+                    display_name: Some("TWW2".into()),
+                }),
+                recipient: EventUser {
+                    id: Some("55554444".into()),
+                    login: "mr_woodchuck".into(),
+                    display_name: Some("Mr_Woodchuck".into()),
+                },
+                plan: SubPlan::Tier1,
+                plan_name: Some("House of Nyoro~n"),
+                gift_months: 1,
+                months: Some(1),
+            }))
+        );
+
+        let tags = "display-name=AnAnonymousGifter;login=ananonymousgifter;msg-id=submysterygift;msg-param-mass-gift-count=5;msg-param-sub-plan=2000";
+        assert_eq!(
+            notice(tags).event(),
+            Ok(UserNoticeEvent::SubMysteryGift(MysteryGift {
+                gifter: None,
+                plan: SubPlan::Tier2,
+                count: 5,
+                sender_count: None,
+            }))
+        );
+
+        let tags = "msg-id=giftpaidupgrade;msg-param-sender-login=museun;msg-param-sender-name=Museun;msg-param-promo-name=Subtember;msg-param-promo-gift-total=3";
+        assert_eq!(
+            notice(tags).event(),
+            Ok(UserNoticeEvent::GiftPaidUpgrade {
+                gifter: EventUser {
+                    id: None,
+                    login: "museun".into(),
+                    display_name: Some("Museun".into()),
+                },
+                promo: Some(Promo {
+                    name: "Subtember",
+                    gift_total: Some(3),
+                }),
+            })
+        );
+
+        let tags = "msg-id=communitypayforward;msg-param-prior-gifter-anonymous=true";
+        assert_eq!(
+            notice(tags).event(),
+            Ok(UserNoticeEvent::CommunityPayForward { prior_gifter: None })
+        );
+
+        let tags = "msg-id=communitypayforward;msg-param-prior-gifter-anonymous=false";
+        assert_eq!(
+            notice(tags).event(),
+            Err(UserNoticeEventError::MissingTag(
+                KnownTag::MsgParamPriorGifterUserName
+            ))
+        );
+    }
+
+    #[test]
+    fn events() {
+        for (tags, expected) in [
+            (
+                "msg-id=raid;msg-param-displayName=TestChannel;msg-param-login=testchannel;msg-param-viewerCount=15;user-id=123456",
+                UserNoticeEvent::Raid {
+                    raider: EventUser {
+                        id: Some("123456".into()),
+                        login: "testchannel".into(),
+                        display_name: Some("TestChannel".into()),
+                    },
+                    viewer_count: 15,
+                },
+            ),
+            (
+                "msg-id=bitsbadgetier;msg-param-threshold=10000",
+                UserNoticeEvent::BitsBadgeTier { threshold: 10000 },
+            ),
+            (
+                "msg-id=announcement;msg-param-color=PURPLE",
+                UserNoticeEvent::Announcement {
+                    color: AnnouncementColor::Purple,
+                },
+            ),
+            (
+                "msg-id=announcement;msg-param-color=RED",
+                UserNoticeEvent::Announcement {
+                    color: AnnouncementColor::Other(Cow::from("RED")),
+                },
+            ),
+            (
+                "msg-id=viewermilestone;msg-param-category=watch-streak;msg-param-value=10;msg-param-copoReward=450",
+                UserNoticeEvent::ViewerMilestone {
+                    category: "watch-streak",
+                    value: 10,
+                    reward: Some(450),
+                },
+            ),
+            (
+                "msg-id=charitydonation;msg-param-charity-name=Example\\sCharity;msg-param-donation-amount=500;msg-param-donation-currency=USD;msg-param-exponent=2",
+                UserNoticeEvent::CharityDonation {
+                    charity_name: "Example Charity",
+                    amount: 500,
+                    currency: "USD",
+                    exponent: 2,
+                },
+            ),
+            (
+                "msg-id=sharedchatnotice;source-msg-id=announcement;msg-param-color=BLUE",
+                UserNoticeEvent::SharedChatNotice(Box::new(UserNoticeEvent::Announcement {
+                    color: AnnouncementColor::Blue,
+                })),
+            ),
+            (
+                "msg-id=some-new-event",
+                UserNoticeEvent::Other(Cow::from("some-new-event")),
+            ),
+        ] {
+            assert_eq!(notice(tags).event(), Ok(expected), "{tags}");
+        }
+
+        assert_eq!(
+            notice("msg-id=sharedchatnotice;source-msg-id=sharedchatnotice").event(),
+            Err(UserNoticeEventError::InvalidTag(KnownTag::SourceMsgId))
+        );
+        assert_eq!(
+            notice("color=#FF0000").event(),
+            Err(UserNoticeEventError::MissingTag(KnownTag::MsgId))
+        );
+    }
+}
@@ -1,6 +1,6 @@
-use std::borrow::Cow;
+use alloc::borrow::Cow;
 
-use crate::{parse_badges, Badge, Color};
+use crate::{known_tags::KnownTag, parse_badges, Badge, Color};
 
 use super::{EmoteSetIdRef, Message, Tags, UserType};
 
@@ -20,7 +20,7 @@ impl<'a> UserState<'a> {
     /// Contains metadata related to the chat badges in the [`badges`](Self::badges) tag.
     pub fn badge_info<'t: 'a>(&'t self) -> impl Iterator<Item = Badge<'a>> + 't {
         self.tags
-            .get("badge-info")
+            .get(KnownTag::BadgeInfo)
             .into_iter()
             .flat_map(parse_badges)
     }
@@ -38,33 +38,33 @@ impl<'a> UserState<'a> {
 
     /// The user’s display name
     pub fn display_name(&self) -> Option<&super::DisplayNameRef> {
-        self.tags.get("display-name").map(Into::into)
+        self.tags.get(KnownTag::DisplayName).map(Into::into)
     }
 
     /// An ID that uniquely identifies the message.
     pub fn msg_id(&self) -> Option<&super::MsgIdRef> {
-        self.tags.get("id").map(Into::into)
+        self.tags.get(KnownTag::Id).map(Into::into)
     }
 
     /// The user is a moderator in the channel
     pub fn is_moderator(&self) -> bool {
-        self.tags.bool("mod")
+        self.tags.bool(KnownTag::Mod)
     }
 
     /// The user is a subscriber of the channel
     pub fn is_subscriber(&self) -> bool {
-        self.tags.bool("subscriber")
+        self.tags.bool(KnownTag::Subscriber)
     }
 
     /// The user has turbo.
     pub fn is_turbo(&self) -> bool {
-        self.tags.bool("turbo")
+        self.tags.bool(KnownTag::Turbo)
     }
 
     /// The user’s type.
     pub fn user_type(&self) -> UserType {
         self.tags
-            .get("user-type")
+            .get(KnownTag::UserType)
             .map(UserType::parse)
             .unwrap_or_default()
     }
@@ -72,7 +72,7 @@ impl<'a> UserState<'a> {
     /// A comma-delimited list of IDs that identify the emote sets that the user has access to. To access the emotes in the set, use the [Get Emote Sets](https://dev.twitch.tv/docs/api/reference#get-emote-sets) API.
     pub fn emote_sets(&self) -> impl Iterator<Item = &EmoteSetIdRef> {
         self.tags
-            .get("emote-sets")
+            .get(KnownTag::EmoteSets)
             .into_iter()
             .flat_map(|s| s.split(','))
             .map(Into::into)
@@ -100,7 +100,7 @@ impl<'a, 'b> TryFrom<&'b Message<'a>> for UserState<'a> {
             raw: value.raw.clone(),
             channel: value
                 .args
-                .get(0)
+                .first()
                 .cloned()
                 .expect("channel attached to message"),
         })
@@ -133,4 +133,45 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn role_helpers() {
+        let input = "@badge-info=subscriber/14;badges=moderator/1,subscriber/12,bits/1000 :tmi.twitch.tv USERSTATE #museun\r\n";
+        let user_state = crate::test_util::parse_as::<UserState>(input);
+
+        assert!(user_state.is_from_moderator());
+        assert!(user_state.is_from_subscriber());
+        assert!(!user_state.is_from_broadcaster());
+        assert_eq!(user_state.subscriber_months(), Some(14));
+        assert_eq!(
+            user_state.known_badges().collect::<Vec<_>>(),
+            [
+                crate::KnownBadge::Moderator,
+                crate::KnownBadge::Subscriber {
+                    tier: crate::messages::SubPlan::Tier1,
+                    months: 12
+                },
+                crate::KnownBadge::Bits(1000),
+            ]
+        );
+
+        // the helpers match on the badge name, like they did before `KnownBadge`
+        let input = "@badges=subscriber/founder :tmi.twitch.tv USERSTATE #museun\r\n";
+        let user_state = crate::test_util::parse_as::<UserState>(input);
+        assert!(user_state.is_from_subscriber());
+        assert!(matches!(
+            user_state.known_badges().next(),
+            Some(crate::KnownBadge::Other(..))
+        ));
+
+        // founders are subscribers
+        let input = "@badges=founder/0 :tmi.twitch.tv USERSTATE #museun\r\n";
+        let user_state = crate::test_util::parse_as::<UserState>(input);
+        assert!(user_state.is_from_subscriber());
+        assert_eq!(user_state.subscriber_months(), Some(1));
+
+        let input = "@badge-info=founder/7;badges=founder/0 :tmi.twitch.tv USERSTATE #museun\r\n";
+        let user_state = crate::test_util::parse_as::<UserState>(input);
+        assert_eq!(user_state.subscriber_months(), Some(7));
+    }
 }
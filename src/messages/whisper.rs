@@ -1,6 +1,6 @@
-use std::borrow::Cow;
+use alloc::{borrow::Cow, vec::Vec};
 
-use crate::{Badge, Color, Emote};
+use crate::{known_tags::KnownTag, Badge, Color, Emote, Fragment};
 
 use super::{Message, Prefix, Tags, UserType};
 
@@ -33,7 +33,7 @@ impl<'a> Whisper<'a> {
 
     /// The user’s display name
     pub fn display_name(&self) -> Option<&super::DisplayNameRef> {
-        self.tags.get("display-name").map(Into::into)
+        self.tags.get(KnownTag::DisplayName).map(Into::into)
     }
 
     /// Emotes in the message.
@@ -41,30 +41,35 @@ impl<'a> Whisper<'a> {
         Emote::from_tags(&self.tags, &self.data)
     }
 
+    /// Split the message into [`Fragment`]s of text, emotes, mentions and links
+    pub fn fragments(&self) -> Vec<Fragment<'_>> {
+        crate::fragments::fragments(&self.tags, &self.data, None)
+    }
+
     /// An ID that uniquely identifies the whisper message.
     pub fn msg_id(&self) -> Option<&super::MsgIdRef> {
-        self.tags.get("message-id").map(Into::into)
+        self.tags.get(KnownTag::MessageId).map(Into::into)
     }
 
     /// An ID that uniquely identifies the whisper thread
     pub fn thread_id(&self) -> Option<&str> {
-        self.tags.get("thread-id")
+        self.tags.get(KnownTag::ThreadId)
     }
 
     /// The ID of the user sending the whisper message.
     pub fn user_id(&self) -> Option<&super::UserIdRef> {
-        self.tags.get("user-id").map(Into::into)
+        self.tags.get(KnownTag::UserId).map(Into::into)
     }
 
     /// User has turbo
     pub fn is_turbo(&self) -> bool {
-        self.tags.bool("turbo")
+        self.tags.bool(KnownTag::Turbo)
     }
 
     /// The type of user sending the whisper message.
     pub fn user_type(&self) -> UserType {
         self.tags
-            .get("user-type")
+            .get(KnownTag::UserType)
             .map(UserType::parse)
             .unwrap_or_default()
     }
@@ -1,5 +1,10 @@
 use super::message::Message;
-use crate::{typed_messages::TypedMessageMarker, Error};
+use crate::{
+    parser::{Anomaly, Diagnostics, ParseMode},
+    typed_messages::TypedMessageMarker,
+    Error, IntoStatic,
+};
+use alloc::{string::String, vec::Vec};
 
 /// A parse trait for parsing a `&mut &str` into some type.
 pub trait Parse<'a>: Sized {
@@ -12,7 +17,7 @@ pub trait Parse<'a>: Sized {
 
 impl<'a, T> Parse<'a> for T
 where
-    T: std::str::FromStr + Sized,
+    T: core::str::FromStr + Sized,
 {
     type Output = Result<T, T::Err>;
 
@@ -57,38 +62,400 @@ pub struct ParseResult<'a> {
 /// For the behavior of the value see [`ParseResult`]
 pub fn parse(mut input: &str) -> Result<ParseResult<'_>, Error> {
     if let Some((mut head, tail)) = input.split_once("\r\n") {
-        return Message::parse(&mut head).map(|msg| ParseResult {
-            remaining: tail,
-            message: msg,
-        });
+        return Message::parse(&mut head)
+            .map(|msg| ParseResult {
+                remaining: tail,
+                message: msg,
+            })
+            .map_err(Error::Parse);
     }
 
     let input = &mut input;
-    Message::parse(input).map(|msg| ParseResult {
-        remaining: input,
-        message: msg,
-    })
+    Message::parse(input)
+        .map(|msg| ParseResult {
+            remaining: input,
+            message: msg,
+        })
+        .map_err(Error::Parse)
 }
 
 /// Parses potentionally many messages from the input.
 ///
+/// Lines can be terminated by either `\r\n` or a bare `\n`. Empty lines are skipped.
+///
 /// This returns an iterator of [`Message`]
 pub fn parse_many(mut input: &str) -> impl Iterator<Item = Result<Message<'_>, Error>> + '_ {
-    std::iter::from_fn(move || {
-        if matches!(input, "" | "\r\n" | "\n") {
+    core::iter::from_fn(move || loop {
+        if input.is_empty() {
             return None;
         }
 
-        match parse(input) {
-            Ok(ParseResult { remaining, message }) => {
-                input = remaining;
-                Some(Ok(message))
-            }
-            Err(err) => Some(Err(err)),
+        // the last line doesn't need a terminator, parse considers it as a *full* message
+        let (line, consumed) = split_line(input.as_bytes()).unwrap_or((input.len(), input.len()));
+        let mut head = &input[..line];
+        input = &input[consumed..];
+
+        if !head.is_empty() {
+            return Some(Message::parse(&mut head).map_err(Error::Parse));
         }
     })
 }
 
+/// How invalid UTF-8 should be handled when parsing bytes
+///
+/// See [`parse_bytes`] and [`Decoder::utf8_policy`]
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub enum Utf8Policy {
+    /// Reject the line with [`Error::InvalidUtf8`]
+    #[default]
+    Reject,
+    /// Replace any invalid sequences with [`U+FFFD`](std::char::REPLACEMENT_CHARACTER)
+    ///
+    /// This allocates if the line was invalid
+    Lossy,
+    /// Like [`Lossy`](Self::Lossy), but only for the data portion of the message.
+    ///
+    /// If the tags, prefix, command or arguments contain invalid UTF-8, the line is rejected with [`Error::InvalidUtf8`]
+    LossyData,
+}
+
+/// # Representation of a possibly partially parse of bytes
+///
+/// This is the byte-oriented version of [`ParseResult`], see [`parse_bytes()`]
+#[derive(Debug, Clone)]
+pub struct ParseBytesResult<'a> {
+    /// The remaining data, if any
+    pub remaining: &'a [u8],
+    /// The parsed messages
+    pub message: Message<'a>,
+}
+
+/// Attempt to parse a message from bytes.
+///
+/// Lines can be terminated by either `\r\n` or a bare `\n`. If no terminator is found, the input is considered as a *full* message.
+///
+/// ```rust
+/// use twitch_message::{parse_bytes, Error, Utf8Policy};
+///
+/// let input = b":museun!museun@museun PRIVMSG #museun :hello \xF0\x9Fworld\r\n";
+///
+/// let err = parse_bytes(input, Utf8Policy::Reject).unwrap_err();
+/// assert!(matches!(err, Error::InvalidUtf8 { offset: 45 }));
+///
+/// let res = parse_bytes(input, Utf8Policy::LossyData)?;
+/// assert_eq!(res.message.data.as_deref(), Some("hello \u{FFFD}world"));
+/// assert!(res.remaining.is_empty());
+/// # Ok::<(),Box<dyn std::error::Error>>(())
+/// ```
+pub fn parse_bytes(input: &[u8], policy: Utf8Policy) -> Result<ParseBytesResult<'_>, Error> {
+    let (line, consumed) = split_line(input).unwrap_or((input.len(), input.len()));
+    parse_line(&input[..line], policy, ParseMode::Lenient).map(|(message, _)| ParseBytesResult {
+        remaining: &input[consumed..],
+        message,
+    })
+}
+
+/// Parses potentionally many messages from the input bytes.
+///
+/// This is the byte-oriented version of [`parse_many`]
+pub fn parse_many_bytes(
+    mut input: &[u8],
+    policy: Utf8Policy,
+) -> impl Iterator<Item = Result<Message<'_>, Error>> + '_ {
+    core::iter::from_fn(move || loop {
+        if input.is_empty() {
+            return None;
+        }
+
+        let (line, consumed) = split_line(input).unwrap_or((input.len(), input.len()));
+        let head = &input[..line];
+        input = &input[consumed..];
+
+        if !head.is_empty() {
+            return Some(parse_line(head, policy, ParseMode::Lenient).map(|(msg, _)| msg));
+        }
+    })
+}
+
+/// A helper parse function for parsing bytes as a specific typed message
+///
+/// This is the byte-oriented version of [`parse_as`]
+pub fn parse_bytes_as<'a, T>(input: &'a [u8], policy: Utf8Policy) -> Result<T, Error>
+where
+    T: TypedMessageMarker<'a>,
+{
+    let msg = parse_bytes(input, policy)?.message;
+    msg.as_typed_message::<T>()
+        .ok_or_else(|| Error::IncorrectMessageType {
+            expected: T::kind(),
+            got: msg.kind.as_str(),
+        })
+}
+
+/// Parses a single line (without its terminator)
+fn parse_line(
+    line: &[u8],
+    policy: Utf8Policy,
+    mode: ParseMode,
+) -> Result<(Message<'_>, Vec<Anomaly>), Error> {
+    fn parse_str<'a>(
+        mut line: &'a str,
+        mode: ParseMode,
+    ) -> Result<(Message<'a>, Vec<Anomaly>), Error> {
+        let mut diag = Diagnostics::new(line, mode);
+        let msg = Message::parse_with(&mut line, &mut diag)?;
+        Ok((msg, diag.anomalies))
+    }
+
+    let offset = match core::str::from_utf8(line) {
+        Ok(line) => return parse_str(line, mode),
+        Err(err) => err.valid_up_to(),
+    };
+
+    match policy {
+        Utf8Policy::Reject => Err(Error::InvalidUtf8 { offset }),
+        Utf8Policy::Lossy => {
+            let line = String::from_utf8_lossy(line);
+            parse_str(&line, mode).map(|(msg, anomalies)| (msg.into_static(), anomalies))
+        }
+        Utf8Policy::LossyData => {
+            // everything up to the data portion has to be valid
+            let head = match data_start(line) {
+                Some(start) if offset >= start => core::str::from_utf8(&line[..start])
+                    .map_err(|_| Error::InvalidUtf8 { offset })?,
+                _ => return Err(Error::InvalidUtf8 { offset }),
+            };
+
+            let (mut msg, anomalies) = parse_str(head, mode)?;
+            msg.raw = String::from_utf8_lossy(line);
+            msg.data = Some(String::from_utf8_lossy(&line[head.len()..]));
+            Ok((msg, anomalies))
+        }
+    }
+}
+
+/// Finds the start of the data portion (the trailing argument) of a line
+fn data_start(mut line: &[u8]) -> Option<usize> {
+    let mut offset = 0;
+    for sigil in [b'@', b':'] {
+        if line.first() == Some(&sigil) {
+            let end = line.iter().position(|&c| c == b' ')? + 1;
+            offset += end;
+            line = &line[end..];
+        }
+    }
+
+    line.windows(2)
+        .position(|w| w == b" :")
+        .map(|pos| offset + pos + 2)
+}
+
+/// Finds the first line terminator in `input`.
+///
+/// This returns the length of the line (without its terminator) and the amount of bytes it consumed (with its terminator)
+fn split_line(input: &[u8]) -> Option<(usize, usize)> {
+    let end = input.iter().position(|&c| c == b'\n')?;
+    let line = match end.checked_sub(1).map(|i| input[i]) {
+        Some(b'\r') => end - 1,
+        _ => end,
+    };
+    Some((line, end + 1))
+}
+
+/// An incremental decoder for reading messages from partial chunks of data.
+///
+/// Data read from a socket rarely lines up with message boundaries. The decoder buffers arbitrary chunks and only yields a [`Message`] once its line terminator (`\r\n` or `\n`) has arrived, keeping any partial tail for the next chunk.
+///
+/// ```rust
+/// use twitch_message::{Decoder, messages::MessageKind};
+///
+/// let mut decoder = Decoder::new();
+/// decoder.push(b":tmi.twitch.tv PING :1234");
+/// // the line hasn't been terminated yet
+/// assert!(decoder.next_message().is_none());
+///
+/// decoder.push(b"567890\r\n:tmi.twitch.tv PING :abc\n:tmi.twitch.tv PI");
+///
+/// let mut tokens = vec![];
+/// while let Some(msg) = decoder.next_message() {
+///     let msg = msg?;
+///     assert_eq!(msg.kind, MessageKind::Ping);
+///     tokens.push(msg.data.unwrap().to_string());
+/// }
+/// assert_eq!(tokens, ["1234567890", "abc"]);
+///
+/// assert!(decoder.next_message().is_none());
+/// assert_eq!(decoder.remaining(), b":tmi.twitch.tv PI");
+/// # Ok::<(),Box<dyn std::error::Error>>(())
+/// ```
+///
+/// The returned message borrows from the decoder. If you need to hold onto it across calls, use [`IntoStatic`](crate::IntoStatic).
+///
+/// Lines longer than [`max_line_length`](Self::max_line_length) are discarded, and reported as an [`Error::LineTooLong`]:
+/// ```rust
+/// use twitch_message::{Decoder, Error};
+///
+/// let mut decoder = Decoder::new().max_line_length(16);
+/// decoder.push(b":tmi.twitch.tv PING :");
+/// assert!(matches!(decoder.next_message(), Some(Err(Error::LineTooLong { max: 16 }))));
+///
+/// // the rest of the line is skipped
+/// decoder.push(b"1234\r\nPING :1\r\n");
+/// assert_eq!(decoder.next_message().unwrap()?.data.as_deref(), Some("1"));
+/// # Ok::<(),Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    buf: Vec<u8>,
+    pos: usize,
+    policy: Utf8Policy,
+    mode: ParseMode,
+    anomalies: Vec<Anomaly>,
+    max_line_length: usize,
+    discarding: bool,
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Self {
+            buf: Vec::new(),
+            pos: 0,
+            policy: Utf8Policy::default(),
+            mode: ParseMode::default(),
+            anomalies: Vec::new(),
+            max_line_length: Self::MAX_LINE_LENGTH,
+            discarding: false,
+        }
+    }
+}
+
+impl Decoder {
+    /// The default maximum length of a line, in bytes.
+    ///
+    /// This is the 8191 bytes IRCv3 allows for tags, plus the 512 bytes allowed for the rest of the message
+    pub const MAX_LINE_LENGTH: usize = 8191 + 512;
+
+    /// Create a new, empty, decoder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new, empty, decoder with an initial buffer capacity
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: Vec::with_capacity(capacity),
+            ..Self::default()
+        }
+    }
+
+    /// Use this [`Utf8Policy`] for lines containing invalid UTF-8.
+    ///
+    /// The default is [`Utf8Policy::Reject`]
+    pub fn utf8_policy(mut self, policy: Utf8Policy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Use this [`ParseMode`] for decoding messages.
+    ///
+    /// The default is [`ParseMode::Lenient`]
+    pub fn mode(mut self, mode: ParseMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// The maximum length of a line, in bytes, not including its terminator.
+    ///
+    /// The default is [`Decoder::MAX_LINE_LENGTH`]
+    pub fn max_line_length(mut self, max: usize) -> Self {
+        self.max_line_length = max;
+        self
+    }
+
+    /// Any anomalies found while decoding the last message
+    ///
+    /// See [`Parser`](crate::Parser)
+    pub fn anomalies(&self) -> &[Anomaly] {
+        &self.anomalies
+    }
+
+    /// Append a chunk of data to the decoder
+    pub fn push(&mut self, data: &[u8]) {
+        self.compact();
+        self.buf.extend_from_slice(data);
+    }
+
+    /// Try to decode the next complete message.
+    ///
+    /// This returns [`None`] if there isn't a complete line buffered.
+    ///
+    /// A line longer than the [`max_line_length`](Self::max_line_length) is returned as an [`Error::LineTooLong`], and the rest of it is skipped.
+    pub fn next_message(&mut self) -> Option<Result<Message<'_>, Error>> {
+        let max = self.max_line_length;
+        let (start, line) = loop {
+            let tail = &self.buf[self.pos..];
+            let Some((line, consumed)) = split_line(tail) else {
+                // a trailing '\r' could be the start of the terminator
+                let pending = tail.len() - usize::from(tail.ends_with(b"\r"));
+                if self.discarding || pending > max {
+                    // don't keep buffering a line that'll be rejected
+                    self.pos = self.buf.len();
+                    if !core::mem::replace(&mut self.discarding, true) {
+                        return Some(Err(Error::LineTooLong { max }));
+                    }
+                }
+                return None;
+            };
+
+            let start = self.pos;
+            self.pos += consumed;
+            if core::mem::take(&mut self.discarding) {
+                continue;
+            }
+            if line > max {
+                return Some(Err(Error::LineTooLong { max }));
+            }
+            if line > 0 {
+                break (start, line);
+            }
+        };
+
+        self.anomalies.clear();
+        let line = &self.buf[start..start + line];
+        Some(
+            parse_line(line, self.policy, self.mode).map(|(msg, anomalies)| {
+                self.anomalies = anomalies;
+                msg
+            }),
+        )
+    }
+
+    /// The buffered data that has not been decoded yet
+    pub fn remaining(&self) -> &[u8] {
+        &self.buf[self.pos..]
+    }
+
+    /// Is there no buffered data left?
+    pub fn is_empty(&self) -> bool {
+        self.remaining().is_empty()
+    }
+
+    /// Discard all of the buffered data
+    pub fn clear(&mut self) {
+        self.buf.clear();
+        self.pos = 0;
+        self.discarding = false;
+    }
+
+    fn compact(&mut self) {
+        if self.pos == 0 {
+            return;
+        }
+        self.buf.drain(..self.pos);
+        self.pos = 0;
+    }
+}
+
 /// A helper parse function for parsing a string as a specific typed message
 ///
 /// For available messages, see the structs in [`messages`](crate::messages#structs)
@@ -103,3 +470,187 @@ where
             got: msg.kind.as_str(),
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::*;
+    use crate::messages::{MessageKind, Privmsg};
+
+    #[test]
+    fn parse_many_terminators() {
+        let input = ":tmi.twitch.tv PING :1\r\n\r\n:tmi.twitch.tv PING :2\n:tmi.twitch.tv PING :3";
+        let tokens = parse_many(input)
+            .map(|msg| msg.unwrap().data.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(tokens, ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn decoder_partial() {
+        let input = b":tmi.twitch.tv PING :1234567890\r\n";
+
+        let mut decoder = Decoder::new();
+        for (i, byte) in input.iter().enumerate() {
+            decoder.push(std::slice::from_ref(byte));
+            if i < input.len() - 1 {
+                assert!(decoder.next_message().is_none());
+            }
+        }
+
+        {
+            let msg = decoder.next_message().unwrap().unwrap();
+            assert_eq!(msg.kind, MessageKind::Ping);
+            assert_eq!(msg.raw, ":tmi.twitch.tv PING :1234567890");
+            assert_eq!(msg.data.as_deref(), Some("1234567890"));
+        }
+
+        assert!(decoder.next_message().is_none());
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn decoder_many() {
+        let mut decoder = Decoder::new();
+        decoder.push(b":tmi.twitch.tv PING :1\r\n\r\n:tmi.twitch.tv PING :2\n:tmi.twitch.tv PI");
+
+        let mut tokens = vec![];
+        while let Some(msg) = decoder.next_message() {
+            tokens.push(msg.unwrap().data.unwrap().to_string());
+        }
+        assert_eq!(tokens, ["1", "2"]);
+        assert_eq!(decoder.remaining(), b":tmi.twitch.tv PI");
+
+        decoder.push(b"NG :3\r");
+        assert!(decoder.next_message().is_none());
+
+        decoder.push(b"\n");
+        {
+            let msg = decoder.next_message().unwrap().unwrap();
+            assert_eq!(msg.data.as_deref(), Some("3"));
+        }
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn decoder_max_line_length() {
+        let mut decoder = Decoder::new().max_line_length(8);
+
+        // a complete line that is too long
+        decoder.push(b"PING :123456789\r\nPING :1\r\n");
+        assert!(matches!(
+            decoder.next_message(),
+            Some(Err(Error::LineTooLong { max: 8 }))
+        ));
+        assert_eq!(
+            decoder.next_message().unwrap().unwrap().data.as_deref(),
+            Some("1")
+        );
+
+        // a line that never ends isn't buffered
+        decoder.push(b"PING :123456789");
+        assert!(matches!(
+            decoder.next_message(),
+            Some(Err(Error::LineTooLong { max: 8 }))
+        ));
+        assert!(decoder.is_empty());
+        for _ in 0..4 {
+            decoder.push(b"123456789");
+            assert!(decoder.next_message().is_none());
+            assert!(decoder.is_empty());
+        }
+        decoder.push(b"\r\nPING :2\r\n");
+        assert_eq!(
+            decoder.next_message().unwrap().unwrap().data.as_deref(),
+            Some("2")
+        );
+
+        // the limit doesn't include the terminator
+        decoder.push(b"PING :12\r");
+        assert!(decoder.next_message().is_none());
+        decoder.push(b"\n");
+        assert_eq!(
+            decoder.next_message().unwrap().unwrap().data.as_deref(),
+            Some("12")
+        );
+        assert!(decoder.next_message().is_none());
+    }
+
+    #[test]
+    fn decoder_invalid_utf8() {
+        let mut decoder = Decoder::new();
+        decoder.push(b":tmi.twitch.tv PING :\xFF\r\n:tmi.twitch.tv PING :1\r\n");
+
+        assert!(matches!(
+            decoder.next_message(),
+            Some(Err(Error::InvalidUtf8 { offset: 21 }))
+        ));
+        let msg = decoder.next_message().unwrap().unwrap();
+        assert_eq!(msg.data.as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn decoder_lossy() {
+        let mut decoder = Decoder::new().utf8_policy(Utf8Policy::Lossy);
+        decoder.push(b":tmi.twitch.tv PING :\xFF\r\n");
+
+        let msg = decoder.next_message().unwrap().unwrap();
+        assert_eq!(msg.data.as_deref(), Some("\u{FFFD}"));
+    }
+
+    #[test]
+    fn decoder_mode() {
+        let input = b"@a=1;=b :tmi.twitch.tv PING :1\r\n";
+
+        let mut decoder = Decoder::new();
+        decoder.push(input);
+        assert!(decoder.next_message().unwrap().is_ok());
+        assert_eq!(decoder.anomalies().len(), 1);
+
+        let mut decoder = Decoder::new().mode(ParseMode::Strict);
+        decoder.push(input);
+        assert!(matches!(
+            decoder.next_message(),
+            Some(Err(Error::Parse(..)))
+        ));
+    }
+
+    #[test]
+    fn parse_bytes_policy() {
+        let input = ":museun!museun@museun PRIVMSG #museun :hello \u{1F600} world\r\n";
+        let res = parse_bytes(input.as_bytes(), Utf8Policy::Reject).unwrap();
+        assert_eq!(res.message, parse(input).unwrap().message);
+        assert!(matches!(res.message.raw, Cow::Borrowed(..)));
+        assert!(matches!(res.message.data, Some(Cow::Borrowed(..))));
+
+        let input = b"@display-name=\xFF :museun!museun@museun PRIVMSG #museun :hello\r\n";
+        assert!(matches!(
+            parse_bytes(input, Utf8Policy::Reject),
+            Err(Error::InvalidUtf8 { offset: 14 })
+        ));
+        assert!(matches!(
+            parse_bytes(input, Utf8Policy::LossyData),
+            Err(Error::InvalidUtf8 { offset: 14 })
+        ));
+
+        let res = parse_bytes(input, Utf8Policy::Lossy).unwrap();
+        assert_eq!(res.message.tags.get("display-name"), Some("\u{FFFD}"));
+        assert_eq!(res.message.data.as_deref(), Some("hello"));
+
+        let input = b":museun!museun@museun PRIVMSG #museun :\xC0hello\n:tmi.twitch.tv PING :1";
+        let res = parse_bytes(input, Utf8Policy::LossyData).unwrap();
+        assert_eq!(res.message.kind, MessageKind::Privmsg);
+        assert_eq!(res.message.args, ["#museun"]);
+        assert_eq!(res.message.data.as_deref(), Some("\u{FFFD}hello"));
+        assert_eq!(res.remaining, b":tmi.twitch.tv PING :1");
+
+        let pm = parse_bytes_as::<Privmsg>(input, Utf8Policy::LossyData).unwrap();
+        assert_eq!(pm.data, "\u{FFFD}hello");
+
+        let messages = parse_many_bytes(input, Utf8Policy::LossyData)
+            .map(|msg| msg.unwrap().kind)
+            .collect::<Vec<_>>();
+        assert_eq!(messages, [MessageKind::Privmsg, MessageKind::Ping]);
+    }
+}
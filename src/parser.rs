@@ -0,0 +1,300 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{messages::Message, Error, ParseComponent, ParseError, ParseErrorReason};
+
+/// How strictly messages should be parsed
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub enum ParseMode {
+    /// Malformed parts of a message are skipped over, and reported as [`Anomaly`]s
+    ///
+    /// This is what [`parse`](fn@crate::parse) and friends use.
+    #[default]
+    Lenient,
+    /// Malformed parts of a message are rejected with an [`Error::Parse`]
+    Strict,
+}
+
+/// Something unexpected found while parsing a message in [`ParseMode::Lenient`]
+///
+/// In [`ParseMode::Strict`] these would have been a [`ParseError`]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Anomaly {
+    /// The byte span in the raw line
+    pub span: Range<usize>,
+    /// The part of the message this was found in
+    pub component: ParseComponent,
+    /// What was unexpected
+    pub reason: ParseErrorReason,
+}
+
+impl core::fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Range { start, end } = self.span;
+        write!(
+            f,
+            "{reason} in the {component} at {start}..{end}",
+            reason = self.reason,
+            component = self.component,
+        )
+    }
+}
+
+/// A configurable parser
+///
+/// ```rust
+/// use twitch_message::{Parser, ParseComponent, ParseErrorReason};
+///
+/// let input = "@badges=;=#FF0000 :museun!museun PRIVMSG #museun :hello\r\n";
+///
+/// // lenient parsing skips the malformed parts, but reports them
+/// let parsed = Parser::lenient().parse(input)?;
+/// assert_eq!(parsed.message.data.as_deref(), Some("hello"));
+/// assert_eq!(parsed.anomalies.len(), 2);
+/// assert_eq!(parsed.anomalies[0].reason, ParseErrorReason::MalformedTag);
+/// assert_eq!(parsed.anomalies[0].span, 9..17);
+/// assert_eq!(parsed.anomalies[1].component, ParseComponent::Prefix);
+///
+/// // strict parsing rejects them
+/// assert!(Parser::strict().parse(input).is_err());
+/// # Ok::<(),Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub struct Parser {
+    mode: ParseMode,
+}
+
+impl Parser {
+    /// Create a new parser with the provided [`ParseMode`]
+    pub const fn new(mode: ParseMode) -> Self {
+        Self { mode }
+    }
+
+    /// Create a new [`ParseMode::Strict`] parser
+    pub const fn strict() -> Self {
+        Self::new(ParseMode::Strict)
+    }
+
+    /// Create a new [`ParseMode::Lenient`] parser
+    pub const fn lenient() -> Self {
+        Self::new(ParseMode::Lenient)
+    }
+
+    /// The [`ParseMode`] of this parser
+    pub const fn mode(&self) -> ParseMode {
+        self.mode
+    }
+
+    /// Attempt to parse a message.
+    ///
+    /// This splits the input the same way [`parse`](fn@crate::parse) does
+    pub fn parse<'a>(&self, input: &'a str) -> Result<Parsed<'a>, Error> {
+        let (mut head, remaining) = input.split_once("\r\n").unwrap_or((input, ""));
+
+        let mut diag = Diagnostics::new(head, self.mode);
+        let message = Message::parse_with(&mut head, &mut diag)?;
+        Ok(Parsed {
+            message,
+            remaining,
+            anomalies: diag.anomalies,
+        })
+    }
+}
+
+/// The output of [`Parser::parse`]
+#[derive(Debug, Clone)]
+pub struct Parsed<'a> {
+    /// The parsed message
+    pub message: Message<'a>,
+    /// The remaining data, if any
+    pub remaining: &'a str,
+    /// Any anomalies found while parsing the message
+    pub anomalies: Vec<Anomaly>,
+}
+
+/// Collects anomalies, or turns them into errors, while parsing a line
+pub(crate) struct Diagnostics<'a> {
+    line: &'a str,
+    mode: ParseMode,
+    pub(crate) anomalies: Vec<Anomaly>,
+}
+
+impl<'a> Diagnostics<'a> {
+    pub(crate) const fn new(line: &'a str, mode: ParseMode) -> Self {
+        Self {
+            line,
+            mode,
+            anomalies: Vec::new(),
+        }
+    }
+
+    /// Create an error for `span`, relative to the `input` (a suffix of the line)
+    pub(crate) fn error(
+        &self,
+        input: &str,
+        span: Range<usize>,
+        component: ParseComponent,
+        reason: ParseErrorReason,
+    ) -> ParseError {
+        let span = self.rebase(input, span);
+        ParseError::new(self.line, span, component, reason)
+    }
+
+    /// Report an anomaly for `span`, relative to the `input` (a suffix of the line)
+    ///
+    /// In strict mode, this produces an error
+    pub(crate) fn report(
+        &mut self,
+        input: &str,
+        span: Range<usize>,
+        component: ParseComponent,
+        reason: ParseErrorReason,
+    ) -> Result<(), ParseError> {
+        if let ParseMode::Strict = self.mode {
+            return Err(self.error(input, span, component, reason));
+        }
+
+        self.anomalies.push(Anomaly {
+            span: self.rebase(input, span),
+            component,
+            reason,
+        });
+        Ok(())
+    }
+
+    fn rebase(&self, input: &str, span: Range<usize>) -> Range<usize> {
+        let offset = self.line.len().saturating_sub(input.len());
+        span.start + offset..span.end + offset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{messages::MessageKind, Prefix};
+
+    #[test]
+    fn lenient() {
+        let input = "@a=1;=2;c=3;d :museun!museun PRIVMSG  #museun :hello\r\n";
+
+        let Parsed {
+            message,
+            remaining,
+            anomalies,
+        } = Parser::lenient().parse(input).unwrap();
+
+        assert!(remaining.is_empty());
+        assert_eq!(message, crate::parse(input).unwrap().message);
+        assert_eq!(message.tags.get("a"), Some("1"));
+        assert_eq!(message.tags.get("c"), Some("3"));
+        assert_eq!(message.tags.get_raw("d"), Some(""));
+        assert_eq!(message.prefix, Prefix::None);
+        assert_eq!(message.kind, MessageKind::Privmsg);
+        assert_eq!(message.args, ["#museun"]);
+
+        assert_eq!(
+            anomalies,
+            [
+                Anomaly {
+                    span: 5..7,
+                    component: ParseComponent::Tags,
+                    reason: ParseErrorReason::MalformedTag
+                },
+                Anomaly {
+                    span: 14..28,
+                    component: ParseComponent::Prefix,
+                    reason: ParseErrorReason::MalformedPrefix
+                },
+                Anomaly {
+                    span: 37..37,
+                    component: ParseComponent::Args,
+                    reason: ParseErrorReason::EmptyArgument
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn lenient_unterminated() {
+        // these are what `parse` returned before parse modes were added
+        for (input, tags, kind, anomaly) in [
+            ("@a=b", None, "@a=b", (0..4, ParseComponent::Tags)),
+            (":prefix", None, ":prefix", (0..7, ParseComponent::Prefix)),
+            (
+                "@a=b :pre",
+                Some("b"),
+                ":pre",
+                (5..9, ParseComponent::Prefix),
+            ),
+        ] {
+            let Parsed {
+                message, anomalies, ..
+            } = Parser::lenient().parse(input).unwrap();
+
+            assert_eq!(message.tags.get("a"), tags, "{input}");
+            assert_eq!(message.prefix, Prefix::None, "{input}");
+            assert_eq!(message.kind, MessageKind::Unknown(kind.into()), "{input}");
+            assert_eq!(message.args, [""], "{input}");
+            assert_eq!(message.data, None, "{input}");
+
+            let (span, component) = anomaly;
+            assert_eq!(
+                anomalies,
+                [Anomaly {
+                    span,
+                    component,
+                    reason: ParseErrorReason::Unterminated
+                }],
+                "{input}"
+            );
+
+            assert!(Parser::strict().parse(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn strict() {
+        for (input, span, component, reason) in [
+            (
+                "@a=1;=2;c=3 :museun!museun@museun PRIVMSG #museun :hello",
+                5..7,
+                ParseComponent::Tags,
+                ParseErrorReason::MalformedTag,
+            ),
+            (
+                ":museun!museun PRIVMSG #museun :hello",
+                0..14,
+                ParseComponent::Prefix,
+                ParseErrorReason::MalformedPrefix,
+            ),
+            (
+                ":museun!museun@museun PRIVMSG #museun  :hello",
+                38..38,
+                ParseComponent::Args,
+                ParseErrorReason::EmptyArgument,
+            ),
+            (
+                "@a=1 :museun!museun@museun",
+                5..26,
+                ParseComponent::Prefix,
+                ParseErrorReason::Unterminated,
+            ),
+        ] {
+            let err = match Parser::strict().parse(input) {
+                Err(Error::Parse(err)) => err,
+                other => panic!("expected a parse error, got: {other:?}"),
+            };
+            assert_eq!(err.span(), span, "{input}");
+            assert_eq!(err.component(), component, "{input}");
+            assert_eq!(err.reason(), reason, "{input}");
+        }
+
+        // IRCv3 allows tags without a value
+        let input = "@a=1;b=;c :museun!museun@museun PRIVMSG #museun :hello\r\n";
+        let parsed = Parser::strict().parse(input).unwrap();
+        assert!(parsed.anomalies.is_empty());
+        assert_eq!(parsed.message.tags.get_raw("b"), Some(""));
+        assert_eq!(parsed.message.tags.get_raw("c"), Some(""));
+        assert_eq!(parsed.message, crate::parse(input).unwrap().message);
+    }
+}
@@ -1,6 +1,9 @@
-use std::borrow::Cow;
+use alloc::borrow::Cow;
 
-use crate::Parse;
+use crate::{
+    parser::{Diagnostics, ParseMode},
+    Parse, ParseComponent, ParseError, ParseErrorReason,
+};
 
 /// An IRC-styled prefix.
 ///
@@ -54,25 +57,49 @@ impl<'a> Prefix<'a> {
 }
 
 impl<'a> Parse<'a> for Prefix<'a> {
-    type Output = Self;
+    type Output = Result<Self, ParseError>;
 
     fn parse(input: &mut &'a str) -> Self::Output {
+        Self::parse_with(input, &mut Diagnostics::new(input, ParseMode::Lenient))
+    }
+}
+
+impl<'a> Prefix<'a> {
+    pub(crate) fn parse_with(
+        input: &mut &'a str,
+        diag: &mut Diagnostics<'_>,
+    ) -> Result<Self, ParseError> {
         if !input.starts_with(':') {
-            return Self::None;
+            return Ok(Self::None);
         }
 
-        let (head, tail) = match input[1..].split_once(' ') {
-            Some(val) => val,
-            None => return Self::None,
+        let start = *input;
+        let Some((head, tail)) = input[1..].split_once(' ') else {
+            // the input is left as is, so the rest of the message is parsed from it
+            diag.report(
+                start,
+                0..start.len(),
+                ParseComponent::Prefix,
+                ParseErrorReason::Unterminated,
+            )?;
+            return Ok(Self::None);
         };
         *input = tail;
 
         let prefix = match head.find('!') {
             Some(bang) => {
                 let name = &head[..bang];
-                let (user, host) = match head[bang + 1..].split_once('@').map(|(k, v)| (k, v)) {
+                let (user, host) = match head[bang + 1..].split_once('@') {
                     Some(val) => val,
-                    None => return Self::None,
+                    None => {
+                        diag.report(
+                            start,
+                            0..head.len() + 1,
+                            ParseComponent::Prefix,
+                            ParseErrorReason::MalformedPrefix,
+                        )?;
+                        return Ok(Self::None);
+                    }
                 };
                 Self::User {
                     name: Cow::from(name),
@@ -85,6 +112,6 @@ impl<'a> Parse<'a> for Prefix<'a> {
             },
         };
 
-        prefix
+        Ok(prefix)
     }
 }
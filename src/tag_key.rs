@@ -0,0 +1,150 @@
+/// A parsed [`Tags`](crate::Tags) key
+///
+/// [IRCv3](https://ircv3.net/specs/extensions/message-tags.html#format) keys look like `[+][vendor/]name`:
+/// - a leading `+` marks a client-only tag (e.g. `+client-nonce`), which the server passes along untouched
+/// - a `vendor/` prefix namespaces the tag (e.g. `twitch.tv/membership`, `+draft/reply`)
+///
+/// ```rust
+/// use twitch_message::TagKey;
+///
+/// let key = TagKey::new("+draft/reply");
+/// assert!(key.is_client_only());
+/// assert_eq!(key.vendor(), Some("draft"));
+/// assert_eq!(key.name(), "reply");
+/// assert_eq!(key.as_str(), "+draft/reply");
+///
+/// let key = TagKey::new("display-name");
+/// assert!(!key.is_client_only());
+/// assert_eq!(key.vendor(), None);
+/// assert_eq!(key.name(), "display-name");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TagKey<'a> {
+    raw: &'a str,
+    client_only: bool,
+    vendor: Option<&'a str>,
+    name: &'a str,
+}
+
+impl<'a> TagKey<'a> {
+    /// Parse a tag key
+    pub fn new(raw: &'a str) -> Self {
+        let (client_only, key) = match raw.strip_prefix('+') {
+            Some(key) => (true, key),
+            None => (false, raw),
+        };
+
+        let (vendor, name) = match key.split_once('/') {
+            Some((vendor, name)) => (Some(vendor), name),
+            None => (None, key),
+        };
+
+        Self {
+            raw,
+            client_only,
+            vendor,
+            name,
+        }
+    }
+
+    /// Determines whether this is a client-only (`+`) tag
+    pub const fn is_client_only(&self) -> bool {
+        self.client_only
+    }
+
+    /// The vendor of this tag, if it has one
+    pub const fn vendor(&self) -> Option<&'a str> {
+        self.vendor
+    }
+
+    /// The name of this tag, without the client-only marker or the vendor
+    pub const fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// The full key, as it appears in the tags
+    pub const fn as_str(&self) -> &'a str {
+        self.raw
+    }
+
+    /// Determines whether this key can be written in the tags
+    ///
+    /// The name must be letters, digits and `-`, and the vendor must be letters, digits, `-` and `.`
+    ///
+    /// ```rust
+    /// use twitch_message::TagKey;
+    ///
+    /// assert!(TagKey::new("+draft/reply").is_valid());
+    /// assert!(TagKey::new("twitch.tv/membership").is_valid());
+    ///
+    /// assert!(!TagKey::new("+").is_valid());
+    /// assert!(!TagKey::new("client nonce").is_valid());
+    /// assert!(!TagKey::new("a=b").is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        fn valid(s: &str, extra: &[u8]) -> bool {
+            !s.is_empty()
+                && s.bytes()
+                    .all(|c| c.is_ascii_alphanumeric() || c == b'-' || extra.contains(&c))
+        }
+
+        valid(self.name, &[]) && self.vendor.map_or(true, |vendor| valid(vendor, b"."))
+    }
+}
+
+impl<'a> From<&'a str> for TagKey<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<'a> core::fmt::Display for TagKey<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        for (input, client_only, vendor, name) in [
+            ("color", false, None, "color"),
+            ("+client-nonce", true, None, "client-nonce"),
+            (
+                "twitch.tv/membership",
+                false,
+                Some("twitch.tv"),
+                "membership",
+            ),
+            ("+draft/reply", true, Some("draft"), "reply"),
+            ("+", true, None, ""),
+        ] {
+            let key = TagKey::new(input);
+            assert_eq!(key.is_client_only(), client_only, "{input}");
+            assert_eq!(key.vendor(), vendor, "{input}");
+            assert_eq!(key.name(), name, "{input}");
+            assert_eq!(key.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn valid() {
+        for input in [
+            "color",
+            "+client-nonce",
+            "twitch.tv/membership",
+            "+draft/reply",
+        ] {
+            assert!(TagKey::new(input).is_valid(), "{input}");
+        }
+
+        for input in [
+            "", "+", "a b", "a;b", "a=b", "++a", "/a", "a/", "a/b/c", "a_b/c", "+a.b",
+        ] {
+            assert!(!TagKey::new(input).is_valid(), "{input}");
+        }
+    }
+}
@@ -1,41 +1,309 @@
-use std::borrow::Cow;
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
 
-use crate::{escape, Color, HashMap, Parse};
+use crate::{
+    escape,
+    known_tags::KnownTag,
+    parser::{Diagnostics, ParseMode},
+    Color, Parse, ParseComponent, ParseError, ParseErrorReason, TagKey,
+};
 
 /// Tags are metadata attached to many Twitch messages.
 ///
 /// These provide a wide assortment of additional data per message.
 ///
-/// Its basically a mapping of a `key` -> `value`
-#[derive(Clone, Default, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+/// Its basically a mapping of a `key` -> `value`, which keeps the order the tags were received (or added) in.
+///
+/// Values are stored in their escaped form and are only [`unescaped`](crate::escape::unescape_tag) when they are looked up.
+///
+/// Twitch sends about 20 tags per message, so lookups are a linear scan.
+#[derive(Clone, Default)]
 pub struct Tags<'a> {
-    pub(crate) inner: HashMap<Cow<'a, str>, Cow<'a, str>>,
+    pub(crate) inner: Vec<Tag<'a>>,
+}
+
+/// The lazily unescaped value of a [`Tag`]
+///
+/// Without `std` there is no `OnceLock`, so escaped values are unescaped when the tag is created
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default)]
+struct Unescaped(std::sync::OnceLock<String>);
+
+#[cfg(feature = "std")]
+impl Unescaped {
+    fn new(_value: &str) -> Self {
+        Self::default()
+    }
+
+    fn get<'s>(&'s self, value: &'s str) -> &'s str {
+        self.0
+            .get_or_init(|| escape::unescape_tag(value).into_owned())
+    }
+
+    fn into_inner(self) -> Option<String> {
+        self.0.into_inner()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+#[derive(Clone, Debug, Default)]
+struct Unescaped(Option<String>);
+
+#[cfg(not(feature = "std"))]
+impl Unescaped {
+    fn new(value: &str) -> Self {
+        Self(match escape::unescape_tag(value) {
+            Cow::Borrowed(..) => None,
+            Cow::Owned(value) => Some(value),
+        })
+    }
+
+    fn get<'s>(&'s self, value: &'s str) -> &'s str {
+        self.0.as_deref().unwrap_or(value)
+    }
+
+    fn into_inner(self) -> Option<String> {
+        self.0
+    }
+}
+
+/// A single `key=value` pair of the [`Tags`]
+#[derive(Clone, Debug)]
+pub(crate) struct Tag<'a> {
+    pub(crate) key: Cow<'a, str>,
+    /// The escaped value, as it appears on the wire
+    pub(crate) value: Cow<'a, str>,
+    unescaped: Unescaped,
+}
+
+impl<'a> Tag<'a> {
+    pub(crate) fn new(key: Cow<'a, str>, value: Cow<'a, str>) -> Self {
+        Self {
+            key,
+            unescaped: Unescaped::new(&value),
+            value,
+        }
+    }
+
+    /// The unescaped value
+    pub(crate) fn value(&self) -> &str {
+        // XXX: the fast path doesn't allocate
+        if !self.value.contains('\\') {
+            return &self.value;
+        }
+
+        self.unescaped.get(&self.value)
+    }
+
+    /// The key and the unescaped value, taking ownership of them
+    fn into_pair(self) -> (Cow<'a, str>, Cow<'a, str>) {
+        if let Some(value) = self.unescaped.into_inner() {
+            return (self.key, Cow::Owned(value));
+        }
+
+        let value = match self.value {
+            Cow::Borrowed(value) => escape::unescape_tag(value),
+            Cow::Owned(value) => match escape::unescape_tag(&value) {
+                Cow::Borrowed(..) => Cow::Owned(value),
+                Cow::Owned(unescaped) => Cow::Owned(unescaped),
+            },
+        };
+        (self.key, value)
+    }
+
+    pub(crate) fn into_static(self) -> Tag<'static> {
+        use crate::IntoStatic as _;
+        Tag {
+            key: self.key.into_static(),
+            value: self.value.into_static(),
+            unescaped: self.unescaped,
+        }
+    }
+}
+
+impl<'a> PartialEq for Tags<'a> {
+    /// Tags are compared as a set, the order they are in doesn't matter
+    fn eq(&self, other: &Self) -> bool {
+        fn contains(left: &Tags<'_>, right: &Tags<'_>) -> bool {
+            left.inner.iter().all(|tag| {
+                right
+                    .inner
+                    .iter()
+                    .any(|other| tag.key == other.key && tag.value() == other.value())
+            })
+        }
+
+        self.inner.len() == other.inner.len() && contains(self, other) && contains(other, self)
+    }
 }
 
-impl<'a> std::fmt::Debug for Tags<'a> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_map().entries(&self.inner).finish()
+impl<'a> Eq for Tags<'a> {}
+
+impl<'a> core::fmt::Debug for Tags<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(self.inner.iter().map(|tag| (&*tag.key, tag.value())))
+            .finish()
     }
 }
 
 impl<'a> Tags<'a> {
     /// Get this `key`'s value as a `&str`
-    pub fn get(&self, key: &str) -> Option<&str> {
-        self.inner.get(key).map(|v| &**v).filter(|s| !s.is_empty())
+    pub fn get(&self, key: impl AsRef<str>) -> Option<&str> {
+        self.get_raw(key).filter(|s| !s.is_empty())
+    }
+
+    /// Get this `key`'s value as a `&str`, even if its empty
+    ///
+    /// Unlike [`Tags::get`], this lets you tell a missing key apart from a key with an empty value.
+    ///
+    /// ```rust
+    /// # use twitch_message::{Tags, Parse as _};
+    /// let tags = Tags::parse(&mut "@badge-info=;color=#FF0000 ").unwrap();
+    /// assert_eq!(tags.get_raw("badge-info"), Some(""));
+    /// assert_eq!(tags.get("badge-info"), None);
+    /// assert_eq!(tags.get_raw("badges"), None);
+    /// ```
+    pub fn get_raw(&self, key: impl AsRef<str>) -> Option<&str> {
+        self.find(key).map(Tag::value)
+    }
+
+    /// Determines whether this `key` exists, even if its value is empty
+    pub fn contains_key(&self, key: impl AsRef<str>) -> bool {
+        self.find(key).is_some()
+    }
+
+    /// The number of tags
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Determines whether there are no tags
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Iterate over the `key` -> `value` pairs, in order
+    ///
+    /// The values are unescaped, and may be empty.
+    pub fn iter(&self) -> TagsIter<'_, 'a> {
+        TagsIter {
+            iter: self.inner.iter(),
+        }
+    }
+
+    /// Iterate over the keys, in order
+    pub fn keys(&self) -> impl Iterator<Item = &str> + '_ {
+        self.inner.iter().map(|tag| &*tag.key)
+    }
+
+    /// Iterate over the keys that aren't a [`KnownTag`], in order
+    ///
+    /// This is useful for finding tags Twitch has started sending, but aren't documented yet.
+    pub fn unknown_keys(&self) -> impl Iterator<Item = &str> + '_ {
+        self.keys().filter(|key| KnownTag::from_key(key).is_none())
+    }
+
+    /// Iterate over the client-only (`+key`) tags, in order
+    ///
+    /// ```rust
+    /// # use twitch_message::{Tags, Parse as _};
+    /// let tags = Tags::parse(&mut "@+client-nonce=abc;color=;+draft/reply=123 ").unwrap();
+    /// let keys = tags.client_only().map(|(key, _)| key.name()).collect::<Vec<_>>();
+    /// assert_eq!(keys, ["client-nonce", "reply"]);
+    /// ```
+    pub fn client_only(&self) -> impl Iterator<Item = (TagKey<'_>, &str)> + '_ {
+        self.tag_keys().filter(|(key, _)| key.is_client_only())
+    }
+
+    /// Iterate over the tags with this `vendor` prefix, in order
+    ///
+    /// ```rust
+    /// # use twitch_message::{Tags, Parse as _};
+    /// let tags = Tags::parse(&mut "@twitch.tv/foo=1;color=;+twitch.tv/bar=2 ").unwrap();
+    /// let keys = tags.vendor("twitch.tv").map(|(key, _)| key.name()).collect::<Vec<_>>();
+    /// assert_eq!(keys, ["foo", "bar"]);
+    /// ```
+    pub fn vendor<'t>(
+        &'t self,
+        vendor: &'t str,
+    ) -> impl Iterator<Item = (TagKey<'t>, &'t str)> + 't {
+        self.tag_keys()
+            .filter(move |(key, _)| key.vendor() == Some(vendor))
+    }
+
+    /// Iterate over the `key` -> `value` pairs, with the keys parsed as a [`TagKey`]
+    pub fn tag_keys(&self) -> impl Iterator<Item = (TagKey<'_>, &str)> + '_ {
+        self.iter().map(|(key, value)| (TagKey::new(key), value))
+    }
+
+    /// Insert this `key` -> `value` mapping, returning the previous value if the `key` existed
+    ///
+    /// The `value` should be unescaped, it'll be escaped when it is written with [`Tags::to_raw`].
+    ///
+    /// An existing `key` keeps its position, otherwise the tag is added to the end.
+    ///
+    /// ```rust
+    /// # use twitch_message::{Tags, Parse as _};
+    /// let mut tags = Tags::parse(&mut "@color=#FF0000;display-name=museun ").unwrap();
+    /// assert_eq!(tags.insert("color", "#00FF00").as_deref(), Some("#FF0000"));
+    /// assert_eq!(tags.insert("system-msg", "hello world"), None);
+    /// assert_eq!(
+    ///     tags.to_raw(),
+    ///     r"@color=#00FF00;display-name=museun;system-msg=hello\sworld"
+    /// );
+    /// ```
+    pub fn insert(
+        &mut self,
+        key: impl Into<Cow<'a, str>>,
+        value: impl Into<Cow<'a, str>>,
+    ) -> Option<Cow<'a, str>> {
+        let tag = Tag::new(key.into(), escape_cow(value.into()));
+        match self.inner.iter().rposition(|old| old.key == tag.key) {
+            Some(pos) => Some(core::mem::replace(&mut self.inner[pos], tag).into_pair().1),
+            None => {
+                self.inner.push(tag);
+                None
+            }
+        }
+    }
+
+    /// Remove this `key`, returning its value if it existed
+    pub fn remove(&mut self, key: impl AsRef<str>) -> Option<Cow<'a, str>> {
+        let key = key.as_ref();
+        let mut value = None;
+        let mut i = 0;
+        while i < self.inner.len() {
+            if self.inner[i].key == key {
+                value.replace(self.inner.remove(i).into_pair().1);
+            } else {
+                i += 1;
+            }
+        }
+        value
+    }
+
+    /// Retain only the tags where `keep` returns true
+    ///
+    /// `keep` is given the `key` and the unescaped `value`
+    pub fn retain(&mut self, mut keep: impl FnMut(&str, &str) -> bool) {
+        self.inner.retain(|tag| keep(&tag.key, tag.value()))
     }
 
     /// Try to get the `key` and parse its value via [`std::str::FromStr`]
-    pub fn parsed<T>(&self, key: &str) -> Option<Result<T, T::Err>>
+    pub fn parsed<T>(&self, key: impl AsRef<str>) -> Option<Result<T, T::Err>>
     where
-        T: std::str::FromStr,
-        T::Err: std::fmt::Display,
+        T: core::str::FromStr,
+        T::Err: core::fmt::Display,
     {
         self.get(key).map(<str>::parse)
     }
 
     /// Get the `key`'s value as a `bool`
-    pub fn bool(&self, key: &str) -> bool {
+    pub fn bool(&self, key: impl AsRef<str>) -> bool {
         self.get(key).filter(|&s| s == "1").is_some()
     }
 
@@ -53,7 +321,7 @@ impl<'a> Tags<'a> {
 
     /// Get the [`Color`] tag
     pub fn color(&self) -> Option<Color> {
-        self.parsed("color").transpose().ok().flatten()
+        self.parsed(KnownTag::Color).transpose().ok().flatten()
     }
 
     /// Create a [`TagsBuilder`]
@@ -62,6 +330,8 @@ impl<'a> Tags<'a> {
     }
 
     /// Format these tags as a raw tags String
+    ///
+    /// The tags are written in the order they were received (or added) in.
     pub fn to_raw(&self) -> String {
         if self.inner.is_empty() {
             return String::new();
@@ -70,72 +340,261 @@ impl<'a> Tags<'a> {
         let cap = self
             .inner
             .iter()
-            .map(|(k, v)| k.len() + escape::estimate_escape_size(v) + v.len() + 2)
+            .map(|tag| tag.key.len() + tag.value.len() + 2)
             .sum::<usize>();
 
         self.inner
             .iter()
             .enumerate()
-            .fold(String::with_capacity(cap), |mut s, (i, (k, v))| {
-                if i == 0 {
-                    s.push('@');
-                }
-                if i > 0 && i != self.inner.len() {
-                    s.push(';')
-                }
-                s.push_str(k);
+            .fold(String::with_capacity(cap), |mut s, (i, tag)| {
+                s.push(if i == 0 { '@' } else { ';' });
+                s.push_str(&tag.key);
                 s.push('=');
-                s.push_str(&escape::escape_tag(v));
+                s.push_str(&tag.value);
                 s
             })
     }
+
+    // XXX: Twitch shouldn't send duplicate keys, but if it does the last one wins
+    fn find(&self, key: impl AsRef<str>) -> Option<&Tag<'a>> {
+        let key = key.as_ref();
+        self.inner.iter().rev().find(|tag| tag.key == key)
+    }
+}
+
+/// An iterator over the `key` -> `value` pairs of [`Tags`]
+///
+/// This is created by [`Tags::iter`]
+#[derive(Clone, Debug)]
+pub struct TagsIter<'t, 'a> {
+    iter: core::slice::Iter<'t, Tag<'a>>,
+}
+
+impl<'t, 'a> Iterator for TagsIter<'t, 'a> {
+    type Item = (&'t str, &'t str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|tag| (&*tag.key, tag.value()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'t, 'a> DoubleEndedIterator for TagsIter<'t, 'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|tag| (&*tag.key, tag.value()))
+    }
+}
+
+impl<'t, 'a> ExactSizeIterator for TagsIter<'t, 'a> {}
+
+/// An owning iterator over the `key` -> `value` pairs of [`Tags`]
+///
+/// This is created by [`Tags::into_iter`]. The values are unescaped.
+#[derive(Clone, Debug)]
+pub struct TagsIntoIter<'a> {
+    iter: alloc::vec::IntoIter<Tag<'a>>,
+}
+
+impl<'a> Iterator for TagsIntoIter<'a> {
+    type Item = (Cow<'a, str>, Cow<'a, str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Tag::into_pair)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for TagsIntoIter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Tag::into_pair)
+    }
+}
+
+impl<'a> ExactSizeIterator for TagsIntoIter<'a> {}
+
+impl<'a> IntoIterator for Tags<'a> {
+    type Item = (Cow<'a, str>, Cow<'a, str>);
+    type IntoIter = TagsIntoIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        TagsIntoIter {
+            iter: self.inner.into_iter(),
+        }
+    }
+}
+
+impl<'t, 'a> IntoIterator for &'t Tags<'a> {
+    type Item = (&'t str, &'t str);
+    type IntoIter = TagsIter<'t, 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> Extend<(K, V)> for Tags<'a>
+where
+    K: Into<Cow<'a, str>>,
+    V: Into<Cow<'a, str>>,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<'a, K, V> FromIterator<(K, V)> for Tags<'a>
+where
+    K: Into<Cow<'a, str>>,
+    V: Into<Cow<'a, str>>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut tags = Self::default();
+        tags.extend(iter);
+        tags
+    }
+}
+
+fn escape_cow(value: Cow<'_, str>) -> Cow<'_, str> {
+    match value {
+        Cow::Borrowed(value) => escape::escape_tag(value),
+        Cow::Owned(value) => match escape::escape_tag(&value) {
+            Cow::Borrowed(..) => Cow::Owned(value),
+            Cow::Owned(escaped) => Cow::Owned(escaped),
+        },
+    }
 }
 
 impl<'a> Parse<'a> for Tags<'a> {
-    type Output = Option<Self>;
+    type Output = Result<Self, ParseError>;
 
     fn parse(input: &mut &'a str) -> Self::Output {
+        Self::parse_with(input, &mut Diagnostics::new(input, ParseMode::Lenient))
+    }
+}
+
+impl<'a> Tags<'a> {
+    pub(crate) fn parse_with(
+        input: &mut &'a str,
+        diag: &mut Diagnostics<'_>,
+    ) -> Result<Self, ParseError> {
         if !input.starts_with('@') {
-            return None;
+            return Ok(Self::default());
         }
 
-        let (head, tail) = input.split_once(' ')?;
+        let start = *input;
+        let Some((head, tail)) = input.split_once(' ') else {
+            // the input is left as is, so the rest of the message is parsed from it
+            diag.report(
+                start,
+                0..start.len(),
+                ParseComponent::Tags,
+                ParseErrorReason::Unterminated,
+            )?;
+            return Ok(Self::default());
+        };
         *input = tail;
 
-        let inner = head[1..]
-            .split_terminator(';')
-            .flat_map(|tag| tag.split_once('='))
-            .map(|(k, v)| (Cow::from(k), escape::unescape_tag(v)))
-            .collect();
+        let head = &head[1..];
+        let mut inner = Vec::with_capacity(head.bytes().filter(|&c| c == b';').count() + 1);
+        let mut pos = 1;
+        for tag in head.split_terminator(';') {
+            // a tag without a value (`key` rather than `key=`) has an empty value
+            match tag.split_once('=').unwrap_or((tag, "")) {
+                ("", _) => diag.report(
+                    start,
+                    pos..pos + tag.len(),
+                    ParseComponent::Tags,
+                    ParseErrorReason::MalformedTag,
+                )?,
+                (k, v) => inner.push(Tag::new(Cow::from(k), Cow::from(v))),
+            }
+            pos += tag.len() + 1;
+        }
 
-        Some(Self { inner })
+        Ok(Self { inner })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> ::serde::Serialize for Tags<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.collect_map(self.inner.iter().map(|tag| (&*tag.key, tag.value())))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 'a> ::serde::Deserialize<'de> for Tags<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        struct Visitor;
+        impl<'de> ::serde::de::Visitor<'de> for Visitor {
+            type Value = Tags<'static>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("a map of tags")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: ::serde::de::MapAccess<'de>,
+            {
+                let mut builder = TagsBuilder::default();
+                while let Some((k, v)) = map.next_entry::<String, String>()? {
+                    builder = builder.add(k, escape::escape_tag(&v));
+                }
+                Ok(builder.finish())
+            }
+        }
+
+        deserializer.deserialize_map(Visitor)
     }
 }
 
 /// A simple builder for constructing tags at runtime
 ///
+/// The tags keep the order they were added in.
+///
 /// ```rust
 /// # use twitch_message::builders::TagsBuilder;
 /// let tags = TagsBuilder::default().add("foo", "bar").add("baz", 42).finish();
 /// assert_eq!(tags.get("foo"), Some("bar"));
 /// assert_eq!(tags.parsed::<usize>("baz"), Some(Ok(42)));
+/// assert_eq!(tags.to_raw(), "@foo=bar;baz=42");
 /// ```
 #[derive(Default, Clone, Debug)]
-pub struct TagsBuilder(HashMap<String, String>);
+pub struct TagsBuilder(Vec<(String, String)>);
 
 impl TagsBuilder {
     /// Add this `key` -> `value` mapping
+    ///
+    /// If the `key` already exists, its value is replaced.
     pub fn add(mut self, k: impl ToString, v: impl ToString) -> Self {
         let k = k.to_string();
         let v = v.to_string();
-        let v = escape::unescape_tag(&v).to_string();
-        self.0.insert(k, v);
+        let v = escape::escape_tag(&escape::unescape_tag(&v)).into_owned();
+        match self.0.iter_mut().find(|(key, _)| *key == k) {
+            Some((_, value)) => *value = v,
+            None => self.0.push((k, v)),
+        }
         self
     }
 
     /// Determines whether a tag key exists in the builder
-    pub fn has(&self, key: &str) -> bool {
-        self.0.contains_key(key)
+    pub fn has(&self, key: impl AsRef<str>) -> bool {
+        self.0.iter().any(|(k, _)| k == key.as_ref())
     }
 
     /// Construct a [`Tags`] from this builder
@@ -144,8 +603,103 @@ impl TagsBuilder {
             inner: self
                 .0
                 .into_iter()
-                .map(|(k, v)| (Cow::from(k), Cow::from(v)))
+                .map(|(k, v)| Tag::new(Cow::from(k), Cow::from(v)))
                 .collect(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let input = r"@badge-info=;badges=broadcaster/1,premium/1;color=#008000;display-name=museun;system-msg=museun\shas\ssubscribed\:\sfor\s6\smonths!;user-type= ";
+
+        let tags = Tags::parse(&mut &*input).unwrap();
+        assert_eq!(tags.to_raw(), input.trim_end());
+
+        assert_eq!(tags.get("badge-info"), None);
+        assert_eq!(tags.get("display-name"), Some("museun"));
+        assert_eq!(
+            tags.get("system-msg"),
+            Some("museun has subscribed; for 6 months!")
+        );
+        assert!(matches!(tags.inner[4].value, Cow::Borrowed(..)));
+    }
+
+    #[test]
+    fn builder_order() {
+        let tags = Tags::builder()
+            .add("z", "1")
+            .add("a", "hello world")
+            .add("m", "3")
+            .add("z", "4")
+            .finish();
+
+        assert_eq!(tags.to_raw(), r"@z=4;a=hello\sworld;m=3");
+        assert_eq!(tags.get("a"), Some("hello world"));
+    }
+
+    #[test]
+    fn eq_ignores_order() {
+        let left = Tags::builder()
+            .add("a", "1")
+            .add("b", "hello world")
+            .finish();
+        let right = Tags::parse(&mut r"@b=hello\sworld;a=1 ").unwrap();
+        assert_eq!(left, right);
+        assert_eq!(left.to_raw(), r"@a=1;b=hello\sworld");
+        assert_eq!(right.to_raw(), r"@b=hello\sworld;a=1");
+
+        let other = Tags::builder().add("a", "1").add("b", "2").finish();
+        assert_ne!(left, other);
+
+        let other = Tags::builder().add("a", "1").finish();
+        assert_ne!(left, other);
+    }
+
+    #[test]
+    fn mutate() {
+        let input =
+            r"@badge-info=;color=#FF0000;display-name=museun;system-msg=hello\sworld;user-type= ";
+        let mut tags = Tags::parse(&mut &*input).unwrap();
+
+        assert_eq!(tags.len(), 5);
+        assert!(tags.contains_key("badge-info"));
+        assert!(!tags.contains_key("badges"));
+        assert_eq!(
+            tags.keys().collect::<Vec<_>>(),
+            [
+                "badge-info",
+                "color",
+                "display-name",
+                "system-msg",
+                "user-type"
+            ]
+        );
+        assert_eq!(tags.iter().nth(3), Some(("system-msg", "hello world")));
+
+        assert_eq!(tags.remove("system-msg").as_deref(), Some("hello world"));
+        assert_eq!(tags.remove("system-msg"), None);
+        assert_eq!(tags.insert("color", "#00FF00").as_deref(), Some("#FF0000"));
+
+        tags.retain(|_, v| !v.is_empty());
+        tags.extend([("msg-id", "a; b")]);
+
+        assert_eq!(
+            tags.to_raw(),
+            r"@color=#00FF00;display-name=museun;msg-id=a\:\sb"
+        );
+        assert_eq!(tags.get("msg-id"), Some("a; b"));
+
+        let pairs = tags.clone().into_iter().collect::<Vec<_>>();
+        assert_eq!(pairs[2], (Cow::from("msg-id"), Cow::from("a; b")));
+        assert_eq!(pairs.into_iter().collect::<Tags>(), tags);
+
+        tags.retain(|_, _| false);
+        assert!(tags.is_empty());
+        assert_eq!(tags.to_raw(), "");
+    }
+}
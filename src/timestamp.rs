@@ -0,0 +1,167 @@
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A UNIX timestamp, in milliseconds, as found in the `tmi-sent-ts` tag
+///
+/// # Examples
+/// ```rust
+/// # use twitch_message::Timestamp;
+/// # use std::time::Duration;
+/// let ts: Timestamp = "1669340813893".parse()?;
+/// assert_eq!(ts.as_millis(), 1669340813893);
+/// assert_eq!(ts.as_duration(), Duration::from_millis(1669340813893));
+///
+/// assert!(ts < Timestamp::from_millis(1669340813894));
+/// assert_eq!(ts.to_string(), "1669340813893");
+/// # Ok::<(),Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Timestamp(u64);
+
+impl Timestamp {
+    /// Create a timestamp from milliseconds since the UNIX epoch
+    pub const fn from_millis(millis: u64) -> Self {
+        Self(millis)
+    }
+
+    /// The milliseconds since the UNIX epoch
+    pub const fn as_millis(&self) -> u64 {
+        self.0
+    }
+
+    /// The [`Duration`] since the UNIX epoch
+    pub const fn as_duration(&self) -> Duration {
+        Duration::from_millis(self.0)
+    }
+
+    /// Convert this to a [`SystemTime`](std::time::SystemTime)
+    ///
+    /// This returns [`None`] if the timestamp is out of range for the platform's `SystemTime`
+    ///
+    /// ```rust
+    /// # use twitch_message::Timestamp;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// let ts = Timestamp::from_millis(1669340813893);
+    /// assert_eq!(ts.to_system_time(), Some(UNIX_EPOCH + Duration::from_millis(1669340813893)));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        UNIX_EPOCH.checked_add(self.as_duration())
+    }
+
+    /// Convert this to a [`time::OffsetDateTime`]
+    ///
+    /// This returns [`None`] if the timestamp is out of range
+    #[cfg(feature = "time")]
+    pub fn to_time(&self) -> Option<time::OffsetDateTime> {
+        time::OffsetDateTime::from_unix_timestamp_nanos(self.0 as i128 * 1_000_000).ok()
+    }
+
+    /// Convert this to a [`chrono::DateTime`]
+    ///
+    /// This returns [`None`] if the timestamp is out of range
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        i64::try_from(self.0)
+            .ok()
+            .and_then(chrono::DateTime::from_timestamp_millis)
+    }
+}
+
+impl From<Timestamp> for Duration {
+    fn from(value: Timestamp) -> Self {
+        value.as_duration()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<Timestamp> for SystemTime {
+    type Error = &'static str;
+    fn try_from(value: Timestamp) -> Result<Self, Self::Error> {
+        value
+            .to_system_time()
+            .ok_or("timestamp is out of range for a SystemTime")
+    }
+}
+
+impl core::ops::Add<Duration> for Timestamp {
+    type Output = Self;
+    /// Offsets the timestamp, saturating at [`u64::MAX`] milliseconds
+    fn add(self, rhs: Duration) -> Self::Output {
+        let millis = u64::try_from(rhs.as_millis()).unwrap_or(u64::MAX);
+        Self(self.0.saturating_add(millis))
+    }
+}
+
+impl core::fmt::Display for Timestamp {
+    /// Formats the milliseconds, the same as the `tmi-sent-ts` tag
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl core::str::FromStr for Timestamp {
+    type Err = &'static str;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        input
+            .parse()
+            .map(Self)
+            .map_err(|_| "invalid millisecond timestamp")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!("0".parse(), Ok(Timestamp::default()));
+        assert_eq!("1669340813893".parse(), Ok(Timestamp(1669340813893)));
+        assert!("-1".parse::<Timestamp>().is_err());
+        assert!("".parse::<Timestamp>().is_err());
+        assert!("1.5".parse::<Timestamp>().is_err());
+    }
+
+    #[test]
+    fn add() {
+        let ts = Timestamp(1000) + Duration::from_secs(2);
+        assert_eq!(ts, Timestamp(3000));
+        assert_eq!(
+            Timestamp(u64::MAX) + Duration::from_millis(1),
+            Timestamp(u64::MAX)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn system_time() {
+        let ts = Timestamp(1669340813893);
+        assert_eq!(
+            ts.to_system_time(),
+            Some(UNIX_EPOCH + Duration::from_millis(1669340813893))
+        );
+        assert_eq!(SystemTime::try_from(ts).ok(), ts.to_system_time());
+        assert_eq!(Timestamp(0).to_system_time(), Some(UNIX_EPOCH));
+    }
+
+    #[test]
+    #[cfg(feature = "time")]
+    fn time() {
+        let ts = Timestamp(1669340813893).to_time().unwrap();
+        assert_eq!(ts.unix_timestamp(), 1669340813);
+        assert_eq!(ts.millisecond(), 893);
+        assert_eq!(Timestamp(u64::MAX).to_time(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn chrono() {
+        let ts = Timestamp(1669340813893).to_chrono().unwrap();
+        assert_eq!(ts.timestamp_millis(), 1669340813893);
+        assert_eq!(Timestamp(u64::MAX).to_chrono(), None);
+    }
+}
@@ -2,9 +2,9 @@
 
 use crate::{
     messages::{
-        Capability, ClearChat, ClearMsg, GlobalUserState, HostTarget, IrcReady, Join, Message,
-        MessageKind, Notice, Part, Ping, Pong, Privmsg, Ready, Reconnect, RoomState, UserNotice,
-        UserState, Whisper,
+        Capability, ClearChat, ClearMsg, EndOfNames, GlobalUserState, HostTarget, IrcReady, Join,
+        Message, MessageKind, Names, Notice, Part, Ping, Pong, Privmsg, Ready, Reconnect,
+        RoomState, UserNotice, UserState, Whisper,
     },
     IntoStatic,
 };
@@ -80,4 +80,6 @@ typed_message! {
     Reconnect       => RECONNECT         ; 15
     Part            => PART              ; 16
     Join            => JOIN              ; 17
+    Names           => NAMES             ; 18
+    EndOfNames      => END_OF_NAMES      ; 19
 }
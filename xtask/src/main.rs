@@ -33,7 +33,7 @@ pub fn walk_dir<'a>(
                 .any(|dir| e.file_name() == dir)
             {
                 return false;
-            } else if e.file_type().map_or(false, |f| f.is_dir()) {
+            } else if e.file_type().is_some_and(|f| f.is_dir()) {
                 return true;
             }
             ext(e.path().extension())
@@ -85,11 +85,45 @@ pub fn deny() {
     }
 }
 
+#[test]
+pub fn no_std() {
+    // the host target has `std`, so this has to check against a target that doesn't
+    const TARGET: &str = "thumbv7em-none-eabihf";
+
+    let installed = Command::new("rustup")
+        .args(["target", "list", "--installed"])
+        .output()
+        .map(|o| {
+            String::from_utf8_lossy(&o.stdout)
+                .lines()
+                .any(|t| t == TARGET)
+        })
+        .unwrap_or(false);
+    if !installed {
+        eprintln!("skipping: {TARGET} is not installed (`rustup target add {TARGET}`)");
+        return;
+    }
+
+    for features in ["", "hashbrown,serde"] {
+        assert!(
+            cargo(["check", "-p", "twitch_message", "--no-default-features"])
+                .args(["--features", features])
+                .args(["--target", TARGET])
+                // a separate target dir so this doesn't wait on the lock held by `cargo test`
+                .args(["--target-dir", "target/no_std"])
+                .status()
+                .unwrap()
+                .success(),
+            "failed to build without std for {TARGET}, features: {features:?}"
+        );
+    }
+}
+
 #[test]
 pub fn ends_with_newline() -> Result<(), eyre::Report> {
     for file in walk_dir(get_cargo_workspace(), &[".git", "target"], |_| true) {
         let file = file?;
-        if !file.file_type().map_or(true, |f| f.is_file()) {
+        if file.file_type().is_some_and(|f| !f.is_file()) {
             continue;
         }
         eprintln!("File: {:?}", file.path());